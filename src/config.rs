@@ -1,12 +1,23 @@
+use crate::validation::ValidationPolicy;
+
+#[derive(Debug, Clone)]
 pub struct Config {
     pub user_agent: String,
     pub timeout_seconds: u64,
     pub delay_between_requests_ms: u64,
     pub max_retries: u32,
     pub base_url: String,
+    pub sitemap_url: String,
     pub batch_size: usize,          // Novo: processar em lotes
     pub pause_after_batches: usize, // Novo: pausar após X lotes
     pub pause_duration_seconds: u64, // Novo: duração da pausa
+    pub exchange_rates_file: String, // Tabela de câmbio local (opcional)
+    pub base_currency: String,
+    pub download_images: bool,       // Miniatura + galeria de fotos
+    pub image_store_dir: String,
+    pub max_gallery_images: usize,   // Fotos da galeria por aparelho
+    pub validation_policy: ValidationPolicy, // Registros com erro de plausibilidade
+    pub overrides_file: String,      // Correções manuais (device_id,field,value)
 }
 
 impl Default for Config {
@@ -17,9 +28,17 @@ impl Default for Config {
             delay_between_requests_ms: 1500,  // 1.5s entre requisições
             max_retries: 3,
             base_url: "https://www.gsmarena.com".to_string(),
+            sitemap_url: "https://www.gsmarena.com/sitemap.xml".to_string(),
             batch_size: 20,          // Processar 20 em 20
             pause_after_batches: 5,  // Pausar após 5 batches (100 requests)
             pause_duration_seconds: 120, // 2 minutos de pausa
+            exchange_rates_file: "exchange_rates.csv".to_string(),
+            base_currency: "EUR".to_string(),
+            download_images: false,
+            image_store_dir: "images".to_string(),
+            max_gallery_images: 10,
+            validation_policy: ValidationPolicy::Keep, // Quarentena/descarte só quando pedido
+            overrides_file: "overrides.csv".to_string(),
         }
     }
 }
@@ -39,6 +58,28 @@ impl Config {
         self
     }
     
+    pub fn with_sitemap_url(mut self, sitemap_url: &str) -> Self {
+        self.sitemap_url = sitemap_url.to_string();
+        self
+    }
+    
+    pub fn with_exchange_rates(mut self, filename: &str, base_currency: &str) -> Self {
+        self.exchange_rates_file = filename.to_string();
+        self.base_currency = base_currency.to_uppercase();
+        self
+    }
+    
+    pub fn with_image_store(mut self, dir: &str) -> Self {
+        self.download_images = true;
+        self.image_store_dir = dir.to_string();
+        self
+    }
+    
+    pub fn with_validation_policy(mut self, policy: ValidationPolicy) -> Self {
+        self.validation_policy = policy;
+        self
+    }
+    
     pub fn with_pause_settings(mut self, pause_after_batches: usize, pause_seconds: u64) -> Self {
         self.pause_after_batches = pause_after_batches;
         self.pause_duration_seconds = pause_seconds;
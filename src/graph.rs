@@ -0,0 +1,236 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use regex::Regex;
+use select::document::Document;
+use select::predicate::{Class, Name};
+
+use crate::models::{DeviceGraph, Phone, RelationKind};
+use crate::utils;
+
+// Link encontrado na página de specs apontando para outro aparelho
+#[derive(Debug, Clone)]
+pub struct RelatedLink {
+    pub device_id: u32,
+    pub model: Option<String>,
+    pub url: Option<String>, // None: comparação sem link de specs na página (só aresta, não é rastreada)
+    pub kind: RelationKind,
+}
+
+// Módulos "Related devices" da barra lateral e links compare.php3?idPhone1=..&idPhone2=..
+pub fn extract_related(html: &str, base_url: &str, own_id: u32) -> Vec<RelatedLink> {
+    let document = Document::from(html);
+    let mut links: Vec<RelatedLink> = Vec::new();
+    
+    for module in document.find(Class("module-phones")) {
+        let heading = module.find(Name("h4"))
+            .next()
+            .map(|h| h.text().to_lowercase())
+            .unwrap_or_default();
+        
+        if !heading.contains("related") {
+            continue;
+        }
+        
+        for node in module.find(Name("a")) {
+            let Some(href) = node.attr("href") else {
+                continue;
+            };
+            let url = format!("{}/{}", base_url, href.trim_start_matches('/'));
+            
+            if let Some(device_id) = utils::device_id_from_url(&url)
+                && utils::is_device_spec_url(&url) {
+                let text = node.text().trim().to_string();
+                links.push(RelatedLink {
+                    device_id,
+                    model: (!text.is_empty()).then_some(text),
+                    url: Some(url),
+                    kind: RelationKind::Related,
+                });
+            }
+        }
+    }
+    
+    // Links de comparação só trazem o ID; a URL vem de algum link de specs do mesmo aparelho na página
+    let mut spec_urls: HashMap<u32, String> = HashMap::new();
+    for href in document.find(Name("a")).filter_map(|a| a.attr("href")) {
+        let url = format!("{}/{}", base_url, href.trim_start_matches('/'));
+        if let Some(device_id) = utils::device_id_from_url(&url)
+            && utils::is_device_spec_url(&url) {
+            spec_urls.entry(device_id).or_insert(url);
+        }
+    }
+    
+    if let Ok(compare_re) = Regex::new(r"idPhone\d=(\d+)") {
+        for node in document.find(Name("a")) {
+            let Some(href) = node.attr("href").filter(|h| h.contains("compare.php")) else {
+                continue;
+            };
+            
+            for cap in compare_re.captures_iter(href) {
+                if let Ok(device_id) = cap[1].parse::<u32>() {
+                    let url = spec_urls.get(&device_id).cloned();
+                    links.push(RelatedLink { device_id, model: None, url, kind: RelationKind::Compare });
+                }
+            }
+        }
+    }
+    
+    links.retain(|link| link.device_id != own_id);
+    links.sort_by_key(|link| (link.device_id, link.kind));
+    links.dedup_by_key(|link| (link.device_id, link.kind));
+    links
+}
+
+// Busca em largura a partir das sementes. `fetch` baixa uma página (None = falhou);
+// toda tentativa conta no budget, com ou sem sucesso. Retorna o grafo e as tentativas
+pub fn crawl(
+    seeds: &[Phone],
+    base_url: &str,
+    max_depth: usize,
+    budget: usize,
+    mut fetch: impl FnMut(&str) -> Option<String>,
+) -> (DeviceGraph, usize) {
+    let mut graph = DeviceGraph::default();
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    let mut attempts = 0;
+    
+    for seed in seeds {
+        if let Some(id) = seed.device_id() {
+            graph.add_node(id, Some(seed.model.clone()), Some(seed.url.clone()), 0);
+            queue.push_back((id, seed.url.clone(), 0));
+        }
+    }
+    
+    while let Some((id, url, depth)) = queue.pop_front() {
+        if !visited.insert(id) {
+            continue;
+        }
+        if attempts >= budget {
+            break;
+        }
+        attempts += 1;
+        
+        let Some(html) = fetch(&url) else {
+            continue;
+        };
+        
+        for link in extract_related(&html, base_url, id) {
+            graph.add_node(link.device_id, link.model, link.url.clone(), depth + 1);
+            graph.add_edge(id, link.device_id, link.kind);
+            
+            if depth + 1 < max_depth
+                && let Some(url) = link.url
+                && !visited.contains(&link.device_id) {
+                queue.push_back((link.device_id, url, depth + 1));
+            }
+        }
+    }
+    
+    (graph, attempts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const BASE: &str = "https://www.gsmarena.com";
+    
+    const SPEC_PAGE: &str = r#"<html><body>
+<div class="module module-phones module-related">
+<h4 class="section-heading">Related devices</h4>
+<a href="samsung_galaxy_s24+-12772.php"><img src="s24p.jpg"><strong>Galaxy S24+</strong></a>
+<a href="samsung_galaxy_s23-12082.php"><img src="s23.jpg"></a>
+<a href="samsung_galaxy_s24-12773.php">Galaxy S24</a>
+</div>
+<div class="module module-phones module-latest">
+<h4 class="section-heading">Latest devices</h4>
+<a href="apple_iphone_15-12559.php">iPhone 15</a>
+</div>
+<a href="compare.php3?idPhone1=12773&amp;idPhone2=12559">Compare with iPhone 15</a>
+<a href="compare.php3?idPhone1=12773&amp;idPhone2=13000">Compare</a>
+<a href="compare.php3?idPhone1=12773&amp;idPhone2=12772">Compare</a>
+</body></html>"#;
+    
+    #[test]
+    fn related_and_compare_links() {
+        let links = extract_related(SPEC_PAGE, BASE, 12773);
+        let found: Vec<(u32, RelationKind, Option<&str>)> = links.iter()
+            .map(|link| (link.device_id, link.kind, link.url.as_deref()))
+            .collect();
+        
+        // Sem o próprio aparelho; a comparação herda a URL de outro link da página quando existe
+        assert_eq!(found, [
+            (12082, RelationKind::Related, Some("https://www.gsmarena.com/samsung_galaxy_s23-12082.php")),
+            (12559, RelationKind::Compare, Some("https://www.gsmarena.com/apple_iphone_15-12559.php")),
+            (12772, RelationKind::Related, Some("https://www.gsmarena.com/samsung_galaxy_s24+-12772.php")),
+            (12772, RelationKind::Compare, Some("https://www.gsmarena.com/samsung_galaxy_s24+-12772.php")),
+            (13000, RelationKind::Compare, None),
+        ]);
+        assert_eq!(links[0].model, None);
+        assert_eq!(links[2].model.as_deref(), Some("Galaxy S24+"));
+    }
+    
+    // Página de specs mínima com o módulo "Related devices"
+    fn related_page(slugs: &[&str]) -> String {
+        let links: String = slugs.iter().map(|slug| format!(r#"<a href="{}.php"></a>"#, slug)).collect();
+        format!(r#"<div class="module-phones"><h4>Related devices</h4>{}</div>"#, links)
+    }
+    
+    // a -> b, c; b -> d; c falha; d -> e
+    fn fixture_fetch(fetched: &mut Vec<String>) -> impl FnMut(&str) -> Option<String> + '_ {
+        move |url: &str| {
+            fetched.push(url.trim_start_matches("https://www.gsmarena.com/").to_string());
+            match url.rsplit('/').next()? {
+                "phone_a-1.php" => Some(related_page(&["phone_b-2", "phone_c-3"])),
+                "phone_b-2.php" => Some(related_page(&["phone_d-4", "phone_a-1"])),
+                "phone_d-4.php" => Some(related_page(&["phone_e-5"])),
+                _ => None,
+            }
+        }
+    }
+    
+    fn seeds() -> Vec<Phone> {
+        vec![Phone {
+            id: 1,
+            model: "Phone A".to_string(),
+            url: "https://www.gsmarena.com/phone_a-1.php".to_string(),
+            status: "done".to_string(),
+            brand: None,
+            thumbnail_url: None,
+        }]
+    }
+    
+    #[test]
+    fn crawl_is_breadth_first_up_to_max_depth() {
+        let mut fetched = Vec::new();
+        let (graph, attempts) = crawl(&seeds(), BASE, 2, 10, fixture_fetch(&mut fetched));
+        
+        // d está na profundidade 2: vira nó mas não é baixado
+        assert_eq!(fetched, ["phone_a-1.php", "phone_b-2.php", "phone_c-3.php"]);
+        assert_eq!(attempts, 3);
+        
+        let depths: Vec<(u32, usize)> = graph.nodes.values().map(|node| (node.id, node.depth)).collect();
+        assert_eq!(depths, [(1, 0), (2, 1), (3, 1), (4, 2)]);
+        
+        let edges: Vec<(u32, u32)> = graph.edges.iter().map(|edge| (edge.source, edge.target)).collect();
+        assert_eq!(edges, [(1, 2), (1, 3), (2, 1), (2, 4)]);
+    }
+    
+    #[test]
+    fn failed_fetches_count_against_the_budget() {
+        let mut fetched = Vec::new();
+        let (graph, attempts) = crawl(&seeds(), BASE, 5, 3, fixture_fetch(&mut fetched));
+        
+        // c falha e ainda assim consome a terceira página; d não é baixado
+        assert_eq!(fetched, ["phone_a-1.php", "phone_b-2.php", "phone_c-3.php"]);
+        assert_eq!(attempts, 3);
+        assert!(!graph.nodes.contains_key(&5));
+        
+        let mut fetched = Vec::new();
+        let (graph, attempts) = crawl(&seeds(), BASE, 5, 10, fixture_fetch(&mut fetched));
+        assert_eq!(fetched.last().map(String::as_str), Some("phone_e-5.php"));
+        assert_eq!(attempts, 5);
+        assert_eq!(graph.nodes[&5].depth, 3);
+    }
+}
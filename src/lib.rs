@@ -0,0 +1,11 @@
+pub mod graph;
+pub mod models;
+pub mod opinions;
+pub mod prices;
+pub mod rankings;
+pub mod review;
+pub mod scraper;
+pub mod specs;
+pub mod utils;
+pub mod validation;
+pub mod config;
\ No newline at end of file
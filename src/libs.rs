@@ -1,4 +0,0 @@
-pub mod models;
-pub mod scraper;
-pub mod utils;
-pub mod config;
\ No newline at end of file
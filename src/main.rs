@@ -1,22 +1,30 @@
-mod config;
-mod models;
-mod scraper;
-mod utils;
+use std::collections::BTreeMap;
 
-use crate::config::Config;
-use crate::scraper::PhoneScraper;
+use gsmarena_scraper::config::Config;
+use gsmarena_scraper::models::PhoneDetails;
+use gsmarena_scraper::scraper::PhoneScraper;
+use gsmarena_scraper::utils;
+use gsmarena_scraper::validation::{self, ValidationPolicy};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("📱 GSM Arena Scraper - 1 por minuto");
     println!("===================================\n");
     
     let config = Config::new();
-    let scraper = PhoneScraper::new(config)?;
+    let scraper = PhoneScraper::new(config.clone())?;
     
     println!("Selecione a operação:");
     println!("1. Coletar URLs");
     println!("2. Extrair detalhes (1 por minuto)");
     println!("3. Sair");
+    println!("4. Descobrir lançamentos (Latest devices / In stores now)");
+    println!("5. Enumerar catálogo completo (sitemap)");
+    println!("6. Extrair detalhes + imagens");
+    println!("7. Coletar reviews");
+    println!("8. Coletar opiniões de usuários");
+    println!("9. Rastrear aparelhos relacionados (grafo)");
+    println!("10. Atualizar histórico de preços");
+    println!("11. Snapshot dos rankings (Top 10)");
     
     let mut input = String::new();
     std::io::stdin().read_line(&mut input)?;
@@ -27,36 +35,154 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             utils::save_phones_to_csv(&phones, "iphone_smartphones_recentes.csv")?;
             println!("✅ {} URLs coletadas.", phones.len());
         }
-        "2" => {
+        "2" => extract_details(&scraper, &config)?,
+        "3" => println!("👋 Saindo..."),
+        "4" => {
             let phones = utils::load_phones_from_csv("iphone_smartphones_recentes.csv")?;
-            println!("📄 {} telefones para processar", phones.len());
-            
-            // Estimar tempo total
-            let total_minutes = phones.len();
-            let total_hours = total_minutes as f32 / 60.0;
-            println!("⏳ Tempo estimado: {} minutos ({:.1} horas)", total_minutes, total_hours);
+            let new_phones = scraper.discover_latest(&phones)?;
             
-            let details = scraper.scrape_one_per_minute(&phones)?;
+            // Fila separada para a extração de detalhes
+            utils::save_phones_to_csv(&new_phones, "novos_smartphones.csv")?;
+            println!("🆕 {} aparelhos novos na fila.", new_phones.len());
+        }
+        "5" => {
+            let phones = scraper.scrape_sitemap_urls()?;
+            utils::save_phones_to_csv(&phones, "catalogo_sitemap.csv")?;
+            println!("✅ {} URLs coletadas do sitemap.", phones.len());
+        }
+        "6" => {
+            // Mesmo fluxo da opção 2, baixando miniatura e galeria para ./images
+            let config = config.clone().with_image_store("images");
+            let scraper = PhoneScraper::new(config.clone())?;
+            extract_details(&scraper, &config)?;
+        }
+        "7" => {
+            let phones = utils::load_phones_from_csv("iphone_smartphones_recentes.csv")?;
+            let reviews = scraper.scrape_reviews(&phones)?;
             
-            // Salvar resultados com timestamp
             let timestamp = chrono::Local::now().format("%Y%m%d_%H%M");
-            let csv_file = format!("results_{}.csv", timestamp);
-            let txt_file = format!("results_{}.txt", timestamp);
+            utils::save_reviews_to_json(&reviews, &format!("reviews_{}.json", timestamp))?;
+        }
+        "8" => {
+            let phones = utils::load_phones_from_csv("iphone_smartphones_recentes.csv")?;
             
-            utils::save_details_to_csv(&details, &csv_file)?;
-            utils::save_details_to_txt(&details, &txt_file)?;
+            println!("Máximo de páginas por aparelho (vazio = todas):");
+            let mut cap = String::new();
+            std::io::stdin().read_line(&mut cap)?;
+            let max_pages = cap.trim().parse().ok();
             
-            // Exibir resumo
-            display_summary(&details);
+            scraper.scrape_opinions(&phones, max_pages, "user_opinions.jsonl")?;
+        }
+        "9" => {
+            let phones = utils::load_phones_from_csv("iphone_smartphones_recentes.csv")?;
+            
+            println!("Profundidade máxima (vazio = 2):");
+            let mut depth = String::new();
+            std::io::stdin().read_line(&mut depth)?;
+            let max_depth = depth.trim().parse().unwrap_or(2);
+            
+            println!("Máximo de páginas baixadas (vazio = 200):");
+            let mut budget = String::new();
+            std::io::stdin().read_line(&mut budget)?;
+            let budget = budget.trim().parse().unwrap_or(200);
+            
+            let graph = scraper.crawl_related(&phones, max_depth, budget)?;
+            
+            utils::save_graph_to_json(&graph, "grafo_relacionados.json")?;
+            utils::save_graph_to_graphml(&graph, "grafo_relacionados.graphml")?;
+            
+            let new_phones = scraper.phones_from_graph(&graph, &phones);
+            utils::save_phones_to_csv(&new_phones, "novos_relacionados.csv")?;
+            println!("🆕 {} aparelhos novos encontrados pelo grafo.", new_phones.len());
+        }
+        "10" => {
+            let phones = utils::load_phones_from_csv("iphone_smartphones_recentes.csv")?;
+            let observations = scraper.scrape_price_history(&phones)?;
+            utils::append_price_history(&observations, "historico_precos.csv")?;
+        }
+        "11" => {
+            println!("Intervalo em horas para repetir (vazio = só uma vez):");
+            let mut interval = String::new();
+            std::io::stdin().read_line(&mut interval)?;
+            
+            match interval.trim().parse::<u64>() {
+                Ok(hours) if hours > 0 => scraper.schedule_rankings(hours, None, "rankings.csv")?,
+                _ => scraper.schedule_rankings(0, Some(1), "rankings.csv")?,
+            }
         }
-        "3" => println!("👋 Saindo..."),
         _ => println!("❌ Opção inválida!"),
     }
     
     Ok(())
 }
 
-fn display_summary(details: &[crate::models::PhoneDetails]) {
+fn extract_details(scraper: &PhoneScraper, config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    let phones = utils::load_phones_from_csv("iphone_smartphones_recentes.csv")?;
+    println!("📄 {} telefones para processar", phones.len());
+    
+    // Perguntar antes da coleta, que pode levar horas
+    println!("Registros com erro de validação: 1. Manter  2. Quarentena  3. Descartar (vazio = manter)");
+    let mut choice = String::new();
+    std::io::stdin().read_line(&mut choice)?;
+    let config = config.clone().with_validation_policy(match choice.trim() {
+        "2" => ValidationPolicy::Quarantine,
+        "3" => ValidationPolicy::Reject,
+        _ => config.validation_policy,
+    });
+    
+    // Estimar tempo total
+    let total_minutes = phones.len();
+    let total_hours = total_minutes as f32 / 60.0;
+    println!("⏳ Tempo estimado: {} minutos ({:.1} horas)", total_minutes, total_hours);
+    
+    let mut details = scraper.scrape_one_per_minute(&phones)?;
+    
+    // Cada coleta de detalhes também alimenta o histórico de preços
+    let observations = scraper.scrape_price_history(&phones)?;
+    utils::append_price_history(&observations, "historico_precos.csv")?;
+    
+    // Correções manuais antes da validação
+    if std::path::Path::new(&config.overrides_file).exists() {
+        let overrides = utils::load_overrides(&config.overrides_file)?;
+        let applied = utils::apply_overrides(&mut details, &overrides);
+        println!("✏️  {} correções manuais aplicadas ({})", applied, config.overrides_file);
+    }
+    
+    // Conversão opcional de preços pela tabela de câmbio local
+    if std::path::Path::new(&config.exchange_rates_file).exists() {
+        let rates = utils::load_exchange_rates(&config.exchange_rates_file, &config.base_currency)?;
+        details.iter_mut().for_each(|d| d.apply_exchange_rates(&rates));
+        println!("💱 Preços convertidos para {} ({})", config.base_currency, config.exchange_rates_file);
+    }
+    
+    // Salvar resultados com timestamp
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M");
+    
+    // Checagens de plausibilidade; registros com erro saem do dataset principal
+    let policy = config.validation_policy;
+    let removed = validation::apply(&mut details, policy);
+    let warnings: usize = details.iter().map(|d| d.validation_issues.len()).sum();
+    println!("🔍 Validação: {} avisos, {} registros retirados", warnings, removed.len());
+    if policy == ValidationPolicy::Quarantine && !removed.is_empty() {
+        utils::save_details_to_json(&removed, &format!("quarentena_{}.json", timestamp))?;
+    }
+    
+    let csv_file = format!("results_{}.csv", timestamp);
+    let txt_file = format!("results_{}.txt", timestamp);
+    let json_file = format!("results_{}.json", timestamp);
+    let index_file = format!("model_index_{}.csv", timestamp);
+    
+    utils::save_details_to_csv(&details, &csv_file)?;
+    utils::save_details_to_txt(&details, &txt_file)?;
+    utils::save_details_to_json(&details, &json_file)?;
+    utils::save_model_index(&details, &index_file)?;
+    
+    // Exibir resumo
+    display_summary(&details);
+    Ok(())
+}
+
+fn display_summary(details: &[PhoneDetails]) {
     let successful = details.iter().filter(|d| d.has_display_info()).count();
     let failed = details.len() - successful;
     
@@ -65,4 +191,21 @@ fn display_summary(details: &[crate::models::PhoneDetails]) {
              (successful as f32 / details.len() as f32) * 100.0);
     println!("  ❌ Falhas: {} ({:.1}%)", failed, 
              (failed as f32 / details.len() as f32) * 100.0);
+    
+    // Origem dos campos de tela (tabela x regex x derivado x manual)
+    println!("\n🧭 PROVENIÊNCIA:");
+    for field in ["screen_size", "display_ratio", "resolution", "ppi", "display_area_cm2"] {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for detail in details {
+            if let Some(provenance) = detail.provenance.get(field) {
+                *counts.entry(provenance.source.kind()).or_default() += 1;
+            }
+        }
+        
+        let summary: Vec<String> = counts.iter().map(|(kind, n)| format!("{} {}", kind, n)).collect();
+        println!("  {}: {}", field, if summary.is_empty() { "-".to_string() } else { summary.join(", ") });
+    }
+    
+    let low_confidence = details.iter().filter(|d| !d.low_confidence_fields().is_empty()).count();
+    println!("  ⚠️  Registros com campos de baixa confiança: {}", low_confidence);
 }
\ No newline at end of file
@@ -1,4 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+
+use crate::specs;
+use crate::utils;
+use crate::validation::ValidationIssue;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Phone {
@@ -6,6 +12,16 @@ pub struct Phone {
     pub model: String,
     pub url: String,
     pub status: String, // "pending", "processed", "error"
+    #[serde(default)]
+    pub brand: Option<String>, // Inferida do slug da URL
+    #[serde(default)]
+    pub thumbnail_url: Option<String>, // <img> da listagem de fabricantes
+}
+
+impl Phone {
+    pub fn device_id(&self) -> Option<u32> {
+        crate::utils::device_id_from_url(&self.url)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +36,53 @@ pub struct PhoneDetails {
     pub resolution: Option<String>,
     pub screen_size: Option<String>,
     pub ppi: Option<String>,
+    pub display_type: Option<String>, // Tecnologia do painel (IPS LCD, Dynamic AMOLED 2X...)
+    pub refresh_rate_hz: Option<u32>,
+    pub hdr_formats: Vec<String>,
+    pub brightness_typical_nits: Option<u32>,
+    pub brightness_peak_nits: Option<u32>,
+    pub display_protection: Option<String>,
+    pub displays: Vec<DisplayPanel>, // Principal + externa/secundária (dobráveis e flips)
+    
+    // Body
+    pub body: BodySpecs,
+    
+    // Platform (os e chipset abaixo guardam o texto original)
+    pub platform: PlatformSpecs,
+    
+    // Memory (ram e storage abaixo resumem os tamanhos distintos)
+    pub memory: MemorySpecs,
+    
+    // Main Camera / Selfie camera
+    pub camera: CameraSpecs,
+    
+    // Battery (battery abaixo guarda o texto original)
+    pub battery_specs: BatterySpecs,
+    
+    // Network / Comms / Features
+    pub connectivity: ConnectivitySpecs,
+    
+    // EU LABEL / Tests
+    pub tests: TestResults,
+    
+    // Misc
+    pub model_codes: Vec<String>,    // SM-A525F, SM-A525F/DS...
+    pub versions: Vec<ModelVersion>, // Quebra regional ("Versions")
+    pub prices: Vec<PriceQuote>,
+    pub price_base: Option<PriceQuote>, // Convertido pela tabela de câmbio local
+    
+    // Cabeçalho da página
+    pub engagement: EngagementStats,
+    pub fetched_at: Option<String>, // RFC 3339, para acompanhar tendências entre coletas
+    
+    // Imagens (armazenamento endereçado por conteúdo)
+    pub thumbnail: Option<ImageAsset>,
+    pub pictures: Vec<ImageAsset>,
+    
+    // Launch
+    pub announced: Option<PartialDate>,
+    pub released: Option<PartialDate>,
+    pub lifecycle_status: Option<LifecycleStatus>,
     
     // Other specs (para futuras expansões)
     pub os: Option<String>,
@@ -28,6 +91,8 @@ pub struct PhoneDetails {
     pub storage: Option<String>,
     pub battery: Option<String>,
     
+    pub provenance: BTreeMap<String, Provenance>, // Campo -> origem + confiança
+    pub validation_issues: Vec<ValidationIssue>, // Preenchido por validation::apply
     pub status_code: u16,
     pub error_message: Option<String>,
 }
@@ -43,11 +108,38 @@ impl PhoneDetails {
             resolution: None,
             screen_size: None,
             ppi: None,
+            display_type: None,
+            refresh_rate_hz: None,
+            hdr_formats: Vec::new(),
+            brightness_typical_nits: None,
+            brightness_peak_nits: None,
+            display_protection: None,
+            displays: Vec::new(),
+            body: BodySpecs::default(),
+            platform: PlatformSpecs::default(),
+            memory: MemorySpecs::default(),
+            camera: CameraSpecs::default(),
+            battery_specs: BatterySpecs::default(),
+            connectivity: ConnectivitySpecs::default(),
+            tests: TestResults::default(),
+            model_codes: Vec::new(),
+            versions: Vec::new(),
+            prices: Vec::new(),
+            price_base: None,
+            engagement: EngagementStats::default(),
+            fetched_at: None,
+            thumbnail: None,
+            pictures: Vec::new(),
+            announced: None,
+            released: None,
+            lifecycle_status: None,
             os: None,
             chipset: None,
             ram: None,
             storage: None,
             battery: None,
+            provenance: BTreeMap::new(),
+            validation_issues: Vec::new(),
             status_code: 0,
             error_message: None,
         }
@@ -56,4 +148,772 @@ impl PhoneDetails {
     pub fn has_display_info(&self) -> bool {
         self.display_ratio.is_some() && self.display_area_cm2.is_some()
     }
-}
\ No newline at end of file
+    
+    // Screen-to-body (%) calculado: área da tela principal / frente do aparelho
+    // (nos dobráveis usa as dimensões abertas, que vêm primeiro)
+    pub fn screen_to_body_ratio(&self) -> Option<f64> {
+        let display_area = self.displays.iter()
+            .find(|d| d.role == DisplayRole::Main)
+            .and_then(|d| d.area_cm2)
+            .or_else(|| self.display_area_cm2.as_deref().and_then(|a| a.parse().ok()))?;
+        
+        let dimensions = self.body.dimensions.first()?;
+        let front_area_cm2 = dimensions.height_mm * dimensions.width_mm / 100.0;
+        
+        if front_area_cm2 <= 0.0 {
+            return None;
+        }
+        
+        Some(display_area / front_area_cm2 * 100.0)
+    }
+    
+    // Preço na moeda base: cotação direta se existir, senão a primeira conversível
+    pub fn apply_exchange_rates(&mut self, rates: &ExchangeRates) {
+        let direct = self.prices.iter().find(|p| p.currency == rates.base_currency);
+        
+        self.price_base = direct
+            .and_then(|p| rates.convert(p))
+            .or_else(|| self.prices.iter().find_map(|p| rates.convert(p)));
+        
+        if self.price_base.is_some() {
+            let source = FieldSource::Derived { from: vec!["prices".to_string(), "exchange_rates".to_string()] };
+            let confidence = if direct.is_some() { Confidence::High } else { Confidence::Medium };
+            self.record_provenance("price_base", source, confidence);
+        }
+    }
+    
+    pub fn record_provenance(&mut self, field: &str, source: FieldSource, confidence: Confidence) {
+        self.provenance.insert(field.to_string(), Provenance { source, confidence });
+    }
+    
+    // Derivado vale o menor nível de confiança entre as entradas
+    pub fn derived_confidence(&self, inputs: &[&str]) -> Confidence {
+        inputs.iter()
+            .map(|field| self.provenance.get(*field).map(|p| p.confidence).unwrap_or(Confidence::Medium))
+            .min()
+            .unwrap_or(Confidence::Medium)
+    }
+    
+    // Correção manual de um campo textual; false se o campo não aceita override
+    pub fn apply_override(&mut self, field: &str, value: &str) -> bool {
+        if field == "model" {
+            self.model = value.to_string();
+        } else {
+            let slot = match field {
+                "screen_size" => &mut self.screen_size,
+                "resolution" => &mut self.resolution,
+                "display_ratio" => &mut self.display_ratio,
+                "ppi" => &mut self.ppi,
+                "display_area_cm2" => &mut self.display_area_cm2,
+                "display_type" => &mut self.display_type,
+                "os" => &mut self.os,
+                "chipset" => &mut self.chipset,
+                "ram" => &mut self.ram,
+                "storage" => &mut self.storage,
+                "battery" => &mut self.battery,
+                _ => return false,
+            };
+            *slot = Some(value.to_string());
+        }
+        
+        self.record_provenance(field, FieldSource::ManualOverride, Confidence::High);
+        
+        // Campos calculados a partir do corrigido não podem ficar com o valor antigo
+        if matches!(field, "screen_size" | "display_ratio" | "resolution") {
+            self.refresh_display_derivations();
+        }
+        true
+    }
+    
+    fn refresh_display_derivations(&mut self) {
+        let area = match (&self.screen_size, &self.display_ratio) {
+            (Some(size), Some(ratio)) => utils::calculate_display_area(size, ratio),
+            _ => None,
+        };
+        
+        if let Some(area) = area {
+            self.display_area_cm2 = Some(format!("{:.2}", area));
+            let confidence = self.derived_confidence(&["screen_size", "display_ratio"]);
+            let source = FieldSource::Derived { from: vec!["screen_size".to_string(), "display_ratio".to_string()] };
+            self.record_provenance("display_area_cm2", source, confidence);
+        }
+        
+        specs::display::sync_main_panel(self);
+    }
+    
+    pub fn low_confidence_fields(&self) -> Vec<&str> {
+        self.provenance.iter()
+            .filter(|(_, p)| p.confidence == Confidence::Low)
+            .map(|(field, _)| field.as_str())
+            .collect()
+    }
+}
+
+// De onde veio o valor de um campo
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FieldSource {
+    TableRow { section: String, label: String }, // Linha da tabela de specs
+    PageHeader,                                  // Cabeçalho da página (hits, fãs...)
+    FallbackRegex { pattern: String },           // Regex sobre o HTML inteiro
+    Derived { from: Vec<String> },               // Calculado a partir de outros campos
+    ManualOverride,                              // Arquivo de correções manuais
+    HttpFetch { url: String },                   // Resposta HTTP (data da coleta, imagens baixadas)
+}
+
+impl FieldSource {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            FieldSource::TableRow { .. } => "table_row",
+            FieldSource::PageHeader => "page_header",
+            FieldSource::FallbackRegex { .. } => "fallback_regex",
+            FieldSource::Derived { .. } => "derived",
+            FieldSource::ManualOverride => "manual_override",
+            FieldSource::HttpFetch { .. } => "http_fetch",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    pub source: FieldSource,
+    pub confidence: Confidence,
+}
+
+// Linha do arquivo de correções manuais (device_id,field,value)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldOverride {
+    pub device_id: u32,
+    pub field: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BodySpecs {
+    pub dimensions: Vec<BodyDimensions>, // Dobráveis: aberto e fechado
+    pub weight_g: Option<f64>,
+    pub front_material: Option<String>,
+    pub back_material: Option<String>,
+    pub frame_material: Option<String>,
+    pub sim: Option<String>,
+    pub esim: bool,
+    pub dual_sim: bool,
+    pub ip_ratings: Vec<String>,
+    pub mil_std: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BodyDimensions {
+    pub state: Option<String>, // "unfolded", "folded" ou None
+    pub height_mm: f64,
+    pub width_mm: f64,
+    pub thickness_mm: f64,
+}
+
+impl BodyDimensions {
+    pub fn summary(&self) -> String {
+        let dims = format!("{} x {} x {} mm", self.height_mm, self.width_mm, self.thickness_mm);
+        match &self.state {
+            Some(state) => format!("{}: {}", state, dims),
+            None => dims,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlatformSpecs {
+    pub os_family: Option<String>,   // Android, iOS, HarmonyOS...
+    pub os_version: Option<String>,  // Versão de lançamento
+    pub os_skin: Option<String>,     // One UI 6.1, MIUI 14...
+    pub upgradable_to: Option<String>,
+    pub upgrade_promise: Option<String>,
+    pub major_upgrades: Option<u32>,
+    pub chipsets: Vec<Chipset>,      // Uma entrada por variante regional
+    pub cpus: Vec<CpuInfo>,
+    pub gpus: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chipset {
+    pub vendor: Option<String>,
+    pub model: String,
+    pub process_nm: Option<f64>,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CpuInfo {
+    pub cores: Option<u32>,
+    pub clusters: Vec<CpuCluster>,
+    pub max_clock_ghz: Option<f64>,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CpuCluster {
+    pub count: u32,
+    pub clock_ghz: f64,
+    pub core: Option<String>, // Cortex-A720, Kryo...
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemorySpecs {
+    pub variants: Vec<MemoryVariant>,
+    pub storage_types: Vec<String>, // UFS 3.1, eMMC 5.1...
+    pub card_slot: Option<String>,
+    pub card_slot_supported: Option<bool>,
+}
+
+impl MemorySpecs {
+    pub fn min_ram_gb(&self) -> Option<f64> {
+        self.variants.iter().filter_map(|v| v.ram_gb).min_by(|a, b| a.total_cmp(b))
+    }
+    
+    pub fn max_ram_gb(&self) -> Option<f64> {
+        self.variants.iter().filter_map(|v| v.ram_gb).max_by(|a, b| a.total_cmp(b))
+    }
+    
+    pub fn min_storage_gb(&self) -> Option<f64> {
+        self.variants.iter().filter_map(|v| v.storage_gb).min_by(|a, b| a.total_cmp(b))
+    }
+    
+    pub fn max_storage_gb(&self) -> Option<f64> {
+        self.variants.iter().filter_map(|v| v.storage_gb).max_by(|a, b| a.total_cmp(b))
+    }
+}
+
+// Uma combinação vendida (ex: "256GB 8GB RAM"); tamanhos em GB
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MemoryVariant {
+    pub storage_gb: Option<f64>,
+    pub ram_gb: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CameraSpecs {
+    pub modules: Vec<CameraModule>, // Traseiras e frontais
+    pub main_features: Vec<String>,
+    pub main_video: Option<VideoCapabilities>,
+    pub selfie_features: Vec<String>,
+    pub selfie_video: Option<VideoCapabilities>,
+}
+
+impl CameraSpecs {
+    pub fn modules_at(&self, position: CameraPosition) -> impl Iterator<Item = &CameraModule> {
+        self.modules.iter().filter(move |m| m.position == position)
+    }
+    
+    pub fn max_megapixels(&self, position: CameraPosition) -> Option<f64> {
+        self.modules_at(position)
+            .filter_map(|m| m.megapixels)
+            .max_by(|a, b| a.total_cmp(b))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CameraPosition {
+    Rear,
+    Front,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CameraRole {
+    Wide,
+    Ultrawide,
+    Telephoto,
+    Periscope,
+    Macro,
+    Depth,
+    Monochrome,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CameraModule {
+    pub position: CameraPosition,
+    pub megapixels: Option<f64>,
+    pub aperture: Option<f64>,        // f/1.8 -> 1.8
+    pub focal_length_mm: Option<f64>, // Equivalente 35mm
+    pub sensor_size: Option<String>,  // 1/1.56"
+    pub role: Option<CameraRole>,
+    pub ois: bool,
+    pub pdaf: bool,
+    pub optical_zoom: Option<f64>,
+}
+
+impl CameraModule {
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        
+        if let Some(mp) = self.megapixels {
+            parts.push(format!("{} MP", mp));
+        }
+        if let Some(aperture) = self.aperture {
+            parts.push(format!("f/{}", aperture));
+        }
+        if let Some(role) = self.role {
+            parts.push(format!("{:?}", role).to_lowercase());
+        }
+        if self.ois {
+            parts.push("OIS".to_string());
+        }
+        
+        parts.join(" ")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoCapabilities {
+    pub max_resolution: String,          // "8K", "4K", "1080p"
+    pub max_resolution_fps: Option<u32>, // Maior fps na resolução máxima
+    pub max_fps: Option<u32>,            // Maior fps em qualquer resolução (câmera lenta)
+    pub modes: Vec<VideoMode>,
+}
+
+impl VideoCapabilities {
+    pub fn summary(&self) -> String {
+        match self.max_resolution_fps {
+            Some(fps) => format!("{}@{}fps", self.max_resolution, fps),
+            None => self.max_resolution.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VideoMode {
+    pub resolution: String,
+    pub fps: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatterySpecs {
+    pub capacity_mah: Option<u32>,
+    pub chemistry: Option<BatteryChemistry>,
+    pub removable: Option<bool>,
+    pub wired_w: Option<f64>,
+    pub wireless_w: Option<f64>,
+    pub reverse_charging: bool,
+    pub reverse_wired_w: Option<f64>,
+    pub reverse_wireless_w: Option<f64>,
+    pub charge_claims: Vec<ChargeClaim>, // "50% in 30 min (advertised)"
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatteryChemistry {
+    LiIon,
+    LiPo,
+    SiliconCarbon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChargeClaim {
+    pub percent: u32,
+    pub minutes: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConnectivitySpecs {
+    pub network_technologies: Vec<String>, // GSM, HSPA, LTE, 5G...
+    pub has_2g: bool,
+    pub has_3g: bool,
+    pub has_4g: bool,
+    pub has_5g: bool,
+    pub bands_2g: Vec<String>,
+    pub bands_3g: Vec<String>,
+    pub bands_4g: Vec<String>,
+    pub bands_5g: Vec<String>,
+    pub wlan: Option<String>,
+    pub wifi_standard: Option<String>, // Wi-Fi 4, 5, 6, 6E, 7
+    pub bluetooth_version: Option<String>,
+    pub nfc: Option<bool>,
+    pub usb_connector: Option<String>,
+    pub usb_version: Option<String>,
+    pub positioning: Vec<String>,
+    pub infrared: Option<bool>,
+    pub fm_radio: Option<bool>,
+    pub headphone_jack: Option<bool>,
+    pub sensors: Vec<String>,
+}
+
+// Medições do GSMArena e etiqueta energética da UE
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TestResults {
+    pub eu_label: Option<EuLabel>,
+    pub benchmarks: Vec<BenchmarkScore>,
+    pub contrast_ratio: Option<String>, // "Infinite" ou "1399:1"
+    pub measured_brightness_nits: Option<u32>,
+    pub loudspeaker_lufs: Option<f64>,
+    pub loudspeaker_rating: Option<String>,
+    pub battery_active_use_hours: Option<f64>, // "Active use score" (teste novo)
+    pub battery_endurance_hours: Option<f64>,  // "Endurance rating" (teste antigo)
+}
+
+impl TestResults {
+    pub fn benchmark(&self, name: &str) -> Option<f64> {
+        self.benchmarks.iter()
+            .find(|b| b.name.eq_ignore_ascii_case(name))
+            .map(|b| b.score)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EuLabel {
+    pub energy_class: Option<String>,
+    pub battery_endurance_hours: Option<f64>,
+    pub battery_cycles: Option<u32>,
+    pub free_fall_class: Option<String>,
+    pub free_fall_count: Option<u32>,
+    pub repairability_class: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkScore {
+    pub name: String,            // AnTuTu, GeekBench, 3DMark
+    pub score: f64,
+    pub variant: Option<String>, // Versão ou teste (v10, Wild Life Extreme)
+}
+
+// Review editorial do GSMArena, ligada ao aparelho pelo ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Review {
+    pub device_id: u32,
+    pub url: String,
+    pub title: String,
+    pub published: Option<String>, // AAAA-MM-DD
+    pub pros: Vec<String>,
+    pub cons: Vec<String>,
+    pub battery_active_use_hours: Option<f64>,
+    pub battery_endurance_hours: Option<f64>,
+    pub display_brightness_nits: Option<u32>,
+    pub page_count: usize,
+}
+
+// Grafo de aparelhos relacionados (nós = IDs do GSMArena)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeviceGraph {
+    pub nodes: BTreeMap<u32, GraphNode>,
+    pub edges: BTreeSet<GraphEdge>,
+}
+
+impl DeviceGraph {
+    // Mantém a primeira profundidade vista, completando modelo/URL se faltarem
+    pub fn add_node(&mut self, id: u32, model: Option<String>, url: Option<String>, depth: usize) {
+        let node = self.nodes.entry(id).or_insert_with(|| GraphNode { id, model: None, url: None, depth });
+        
+        if node.model.is_none() {
+            node.model = model;
+        }
+        if node.url.is_none() {
+            node.url = url;
+        }
+    }
+    
+    pub fn add_edge(&mut self, source: u32, target: u32, kind: RelationKind) {
+        self.edges.insert(GraphEdge { source, target, kind });
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub id: u32,
+    pub model: Option<String>,
+    pub url: Option<String>,
+    pub depth: usize, // Distância até o aparelho semente mais próximo
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub source: u32,
+    pub target: u32,
+    pub kind: RelationKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum RelationKind {
+    Related, // "Related devices"
+    Compare, // Sugestões de comparação
+}
+
+// Oferta de loja na página de preços, com a data da coleta (histórico)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceObservation {
+    pub observed_at: String, // RFC 3339
+    pub device_id: u32,
+    pub store: String,
+    pub region: String,
+    pub currency: String,
+    pub amount: f64,
+    pub variant: Option<String>, // "256GB 8GB RAM"
+}
+
+// Linha do snapshot diário dos rankings da home
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankingEntry {
+    pub snapshot_date: String, // AAAA-MM-DD
+    pub captured_at: String,   // RFC 3339
+    pub list: RankingList,
+    pub rank: usize,
+    pub device_id: u32,
+    pub model: String,
+    pub value: Option<u64>, // Hits diários ou fãs, conforme a lista
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RankingList {
+    DailyInterest,
+    Fans,
+}
+
+// Comentário da página de opiniões (uma linha do JSONL)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserOpinion {
+    pub device_id: u32,
+    pub comment_id: Option<String>,
+    pub page: usize,
+    pub author: String,
+    pub location: Option<String>,
+    pub date: Option<String>, // Como exibido: "05 Feb 2024", "2 hours ago"
+    pub votes: Option<i32>,
+    pub rating: Option<f64>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageAsset {
+    pub source_url: String,
+    pub local_path: String, // images/ab/abcdef....jpg
+    pub sha256: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EngagementStats {
+    pub hits: Option<u64>,
+    pub popularity_percent: Option<f64>,
+    pub trend: Option<PopularityTrend>,
+    pub fans: Option<u32>,
+    pub opinions_count: Option<u32>,
+    pub opinions_url: Option<String>,
+    pub review_url: Option<String>, // Review editorial ("-review-")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PopularityTrend {
+    Up,
+    Down,
+    Stable,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelVersion {
+    pub code: String,
+    pub regions: Option<String>, // "Global", "USA, Canada"
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceQuote {
+    pub currency: String, // Código ISO 4217 (USD, EUR, INR...)
+    pub amount: f64,
+    pub approximate: bool, // "About 200 EUR"
+}
+
+impl PriceQuote {
+    pub fn summary(&self) -> String {
+        let prefix = if self.approximate { "~" } else { "" };
+        format!("{}{:.2} {}", prefix, self.amount, self.currency)
+    }
+}
+
+// Tabela de câmbio local: 1 unidade da moeda = rate unidades da moeda base
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeRates {
+    pub base_currency: String,
+    pub rates: HashMap<String, f64>,
+}
+
+impl ExchangeRates {
+    pub fn convert(&self, quote: &PriceQuote) -> Option<PriceQuote> {
+        let rate = if quote.currency == self.base_currency {
+            1.0
+        } else {
+            *self.rates.get(&quote.currency)?
+        };
+        
+        Some(PriceQuote {
+            currency: self.base_currency.clone(),
+            amount: quote.amount * rate,
+            approximate: quote.approximate,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisplayRole {
+    Main,
+    Cover,
+    Secondary,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayPanel {
+    pub role: DisplayRole,
+    pub size_inches: Option<f64>,
+    pub width_px: Option<u32>,
+    pub height_px: Option<u32>,
+    pub ratio: Option<String>,
+    pub area_cm2: Option<f64>,               // Calculada (retângulo perfeito)
+    pub ppi: Option<u32>,
+    pub reported_area_cm2: Option<f64>,      // "~94.4 cm2" do GSMArena
+    pub reported_screen_to_body: Option<f64>, // "~90.9% screen-to-body ratio"
+    pub area_discrepancy_cm2: Option<f64>,   // Calculada - informada
+    pub area_mismatch: bool,                 // Diferença acima da tolerância
+    pub geometry: Option<DisplayGeometry>,   // Derivada de tamanho + resolução + OS
+}
+
+impl DisplayPanel {
+    pub fn new(role: DisplayRole) -> Self {
+        Self {
+            role,
+            size_inches: None,
+            width_px: None,
+            height_px: None,
+            ratio: None,
+            area_cm2: None,
+            ppi: None,
+            reported_area_cm2: None,
+            reported_screen_to_body: None,
+            area_discrepancy_cm2: None,
+            area_mismatch: false,
+            geometry: None,
+        }
+    }
+    
+    // Resumo em uma linha para CSV/TXT (ex: "Cover: 6.3\" 968x2376 21:9 410 ppi 95.21 cm²")
+    pub fn summary(&self) -> String {
+        let mut parts = vec![format!("{:?}:", self.role)];
+        
+        if let Some(size) = self.size_inches {
+            parts.push(format!("{}\"", size));
+        }
+        if let (Some(w), Some(h)) = (self.width_px, self.height_px) {
+            parts.push(format!("{}x{}", w, h));
+        }
+        if let Some(ratio) = &self.ratio {
+            parts.push(ratio.clone());
+        }
+        if let Some(ppi) = self.ppi {
+            parts.push(format!("{} ppi", ppi));
+        }
+        if let Some(area) = self.area_cm2 {
+            parts.push(format!("{:.2} cm²", area));
+        }
+        if self.area_mismatch {
+            parts.push("(área diverge do GSMArena)".to_string());
+        }
+        
+        parts.join(" ")
+    }
+}
+
+// Dimensões físicas (retrato: largura = lado menor) e viewport CSS estimado
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayGeometry {
+    pub width_mm: f64,
+    pub height_mm: f64,
+    pub width_in: f64,
+    pub height_in: f64,
+    pub pixel_pitch_mm: f64,
+    pub device_pixel_ratio: f64, // Heurística por OS
+    pub css_width: u32,
+    pub css_height: u32,
+}
+
+impl DisplayGeometry {
+    pub fn css_viewport(&self) -> String {
+        format!("{}x{}", self.css_width, self.css_height)
+    }
+}
+
+// Data parcial do GSMArena: "2021, March 17", "2021, March", "2025, Q3" ou só "2021"
+// Serializada como texto ("2021-03-17", "2021-03", "2025-Q3", "2021")
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct PartialDate {
+    pub year: i32,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+    pub quarter: Option<u32>,
+}
+
+impl PartialDate {
+    // Chave de ordenação (ex: sort_by_key): trimestre conta como o primeiro mês dele.
+    // Sem Ord de propósito: "2025-Q3" e "2025-07" ordenam juntos mas não são iguais
+    pub fn sort_key(&self) -> (i32, u32, u32) {
+        let month = self.month
+            .or_else(|| self.quarter.map(|q| (q - 1) * 3 + 1))
+            .unwrap_or(0);
+        (self.year, month, self.day.unwrap_or(0))
+    }
+}
+
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.month, self.day, self.quarter) {
+            (Some(month), Some(day), _) => write!(f, "{}-{:02}-{:02}", self.year, month, day),
+            (Some(month), None, _) => write!(f, "{}-{:02}", self.year, month),
+            (None, _, Some(quarter)) => write!(f, "{}-Q{}", self.year, quarter),
+            _ => write!(f, "{}", self.year),
+        }
+    }
+}
+
+impl From<PartialDate> for String {
+    fn from(date: PartialDate) -> Self {
+        date.to_string()
+    }
+}
+
+impl TryFrom<String> for PartialDate {
+    type Error = String;
+    
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("Data parcial inválida: {}", text);
+        let mut parts = text.split('-');
+        
+        let year = parts.next()
+            .and_then(|p| p.parse().ok())
+            .ok_or_else(invalid)?;
+        let mut date = PartialDate { year, month: None, day: None, quarter: None };
+        
+        if let Some(part) = parts.next() {
+            if let Some(quarter) = part.strip_prefix('Q') {
+                date.quarter = Some(quarter.parse().map_err(|_| invalid())?);
+            } else {
+                date.month = Some(part.parse().map_err(|_| invalid())?);
+            }
+        }
+        
+        if let Some(part) = parts.next() {
+            date.day = Some(part.parse().map_err(|_| invalid())?);
+        }
+        
+        Ok(date)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LifecycleStatus {
+    Available,
+    ComingSoon,
+    Rumored,
+    Cancelled,
+    Discontinued,
+}
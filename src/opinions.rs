@@ -0,0 +1,128 @@
+use regex::Regex;
+use select::document::Document;
+use select::predicate::{Class, Name, Predicate};
+
+use crate::models::UserOpinion;
+use crate::utils;
+
+// "modelo-12773.php" -> "modelo-reviews-12773.php" (página 1 das opiniões)
+pub fn opinions_url(phone_url: &str) -> Option<String> {
+    utils::device_subpage_url(phone_url, "reviews")
+}
+
+// Páginas: "modelo-reviews-12773.php", "modelo-reviews-12773p2.php", ...
+pub const PAGE_MARKER: &str = "-reviews-";
+
+// Cada comentário é um div.user-thread
+pub fn parse_opinions(html: &str, device_id: u32, page: usize) -> Vec<UserOpinion> {
+    let document = Document::from(html);
+    let Ok(rating_re) = Regex::new(r"(\d+(?:\.\d+)?)\s*/\s*(?:5|10)") else {
+        return Vec::new();
+    };
+    
+    document.find(Name("div").and(Class("user-thread")))
+        .filter_map(|thread| {
+            // Respostas trazem a citação em span.uinreply; fica só o texto do autor
+            let text: String = thread.find(Class("uopin")).next()?
+                .children()
+                .filter(|child| !child.attr("class").is_some_and(|c| c.contains("uinreply")))
+                .map(|child| child.text())
+                .collect::<String>()
+                .trim()
+                .to_string();
+            
+            let author = thread.find(Class("uname")).next()
+                .or_else(|| thread.find(Class("uname2")).next())
+                .map(|node| node.text().trim().to_string())
+                .unwrap_or_else(|| "Anonymous".to_string());
+            
+            let date = thread.find(Class("upost")).next()
+                .map(|node| node.text().trim().to_string())
+                .filter(|d| !d.is_empty());
+            
+            let votes = thread.find(Class("thumbs-score")).next()
+                .and_then(|node| node.text().trim().parse().ok());
+            
+            // Nota só aparece em alguns comentários ("Rating: 4/5")
+            let rating = thread.find(Class("urating")).next()
+                .and_then(|node| rating_re.captures(&node.text()).and_then(|cap| cap[1].parse().ok()));
+            
+            Some(UserOpinion {
+                device_id,
+                comment_id: thread.attr("id").map(|id| id.to_string()),
+                page,
+                author,
+                location: thread.find(Class("ulocation")).next().map(|node| node.text().trim().to_string()),
+                date,
+                votes,
+                rating,
+                text,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const PAGE: &str = r#"<html><body>
+<div id="all-opinions">
+<div class="user-thread" id="3051234">
+<ul class="uinfo2"><li class="uname"><b>Marcos</b></li><li class="ulocation">Cdr</li><li class="upost">05 Feb 2024</li></ul>
+<span class="thumbs-score">12</span>
+<p class="uopin">Battery lasts two days with light use.</p>
+<div class="urating">Rating: 4/5</div>
+</div>
+<div class="user-thread" id="3051240">
+<ul class="uinfo2"><li class="uname2">Anonymous</li><li class="upost">2 hours ago</li></ul>
+<p class="uopin"><span class="uinreply">Marcos, 05 Feb 2024Battery lasts two days...</span>Not mine, barely one day.</p>
+</div>
+<div class="user-thread" id="3051241">
+<ul class="uinfo2"><li class="upost">1 hour ago</li></ul>
+</div>
+</div>
+<div class="nav-pages">
+<a href="samsung_galaxy_s24-reviews-12773p2.php">2</a>
+<a href="samsung_galaxy_s24-reviews-12773p3.php">3</a>
+<a href="samsung_galaxy_s24-review-2664p5.php">Review</a>
+</div>
+</body></html>"#;
+    
+    #[test]
+    fn threads_replies_and_ratings() {
+        let opinions = parse_opinions(PAGE, 12773, 2);
+        
+        // Sem div.uopin o comentário é descartado
+        assert_eq!(opinions.len(), 2);
+        
+        let first = &opinions[0];
+        assert_eq!(first.comment_id.as_deref(), Some("3051234"));
+        assert_eq!((first.device_id, first.page), (12773, 2));
+        assert_eq!(first.author, "Marcos");
+        assert_eq!(first.location.as_deref(), Some("Cdr"));
+        assert_eq!(first.date.as_deref(), Some("05 Feb 2024"));
+        assert_eq!(first.votes, Some(12));
+        assert_eq!(first.rating, Some(4.0));
+        assert_eq!(first.text, "Battery lasts two days with light use.");
+        
+        // A citação da resposta não entra no texto
+        let reply = &opinions[1];
+        assert_eq!(reply.author, "Anonymous");
+        assert_eq!(reply.location, None);
+        assert_eq!((reply.votes, reply.rating), (None, None));
+        assert_eq!(reply.text, "Not mine, barely one day.");
+    }
+    
+    #[test]
+    fn opinion_pages() {
+        let first = opinions_url("https://www.gsmarena.com/samsung_galaxy_s24-12773.php").unwrap();
+        assert_eq!(first, "https://www.gsmarena.com/samsung_galaxy_s24-reviews-12773.php");
+        assert_eq!(utils::paged_url(&first, 1), first);
+        assert_eq!(utils::paged_url(&first, 3), "https://www.gsmarena.com/samsung_galaxy_s24-reviews-12773p3.php");
+        
+        // A paginação da review editorial ("-review-") não conta
+        assert_eq!(utils::page_count(PAGE, PAGE_MARKER), 3);
+        assert_eq!(utils::page_count("<html></html>", PAGE_MARKER), 1);
+    }
+}
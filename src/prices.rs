@@ -0,0 +1,136 @@
+use select::document::Document;
+use select::node::Node;
+use select::predicate::Name;
+
+use crate::models::PriceObservation;
+use crate::specs::misc::parse_prices;
+
+// Tabelas table.pricing da página de preços: uma por região,
+// cabeçalho com as variantes (128GB 8GB RAM...) e uma linha por loja
+pub fn parse_price_page(html: &str, device_id: u32, observed_at: &str) -> Vec<PriceObservation> {
+    let document = Document::from(html);
+    let mut observations = Vec::new();
+    
+    let tables = document.find(Name("table"))
+        .filter(|table| table.attr("class").is_some_and(|c| c.contains("pricing")));
+    
+    for table in tables {
+        let region = table_region(&table);
+        let variants: Vec<String> = table.find(Name("th"))
+            .map(|th| th.text().trim().to_string())
+            .collect();
+        
+        for tr in table.find(Name("tr")) {
+            let cells: Vec<Node> = tr.find(Name("td")).collect();
+            let Some(store_cell) = cells.first() else {
+                continue;
+            };
+            
+            // Lojas aparecem como logo (<img alt>) ou texto
+            let store = store_cell.find(Name("img")).next()
+                .and_then(|img| img.attr("alt"))
+                .map(|alt| alt.trim().to_string())
+                .unwrap_or_else(|| store_cell.text().trim().to_string());
+            
+            if store.is_empty() {
+                continue;
+            }
+            
+            for (i, cell) in cells.iter().enumerate().skip(1) {
+                let Some(quote) = parse_prices(&cell.text()).into_iter().next() else {
+                    continue;
+                };
+                
+                // A primeira coluna do cabeçalho é a da loja (às vezes vazia/ausente)
+                let variant = if variants.len() == cells.len() {
+                    variants.get(i)
+                } else {
+                    variants.get(i - 1)
+                };
+                
+                observations.push(PriceObservation {
+                    observed_at: observed_at.to_string(),
+                    device_id,
+                    store: store.clone(),
+                    region: region.clone(),
+                    currency: quote.currency,
+                    amount: quote.amount,
+                    variant: variant.filter(|v| !v.is_empty()).cloned(),
+                });
+            }
+        }
+    }
+    
+    observations
+}
+
+// Região: <caption> da tabela ou o título (h2/h3) imediatamente anterior
+fn table_region(table: &Node) -> String {
+    if let Some(caption) = table.find(Name("caption")).next() {
+        return caption.text().trim().to_string();
+    }
+    
+    let mut sibling = table.prev();
+    while let Some(node) = sibling {
+        if matches!(node.name(), Some("h2") | Some("h3") | Some("h4")) {
+            return node.text().trim().to_string();
+        }
+        sibling = node.prev();
+    }
+    
+    "Global".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const PRICE_PAGE: &str = r##"<html><body>
+<div class="pricing-container">
+<h2 class="pricing-header">United States</h2>
+<table class="pricing inline widelabel">
+<thead><tr><th></th><th>128GB 8GB RAM</th><th>256GB 8GB RAM</th></tr></thead>
+<tbody>
+<tr><td><img src="amazon.png" alt="Amazon "></td><td><a href="#">$ 799.99</a></td><td><a href="#">$ 859.99</a></td></tr>
+<tr><td>Best Buy</td><td>$ 799.99</td><td>-</td></tr>
+<tr><td></td><td>$ 1.00</td></tr>
+</tbody>
+</table>
+<table class="pricing inline widelabel">
+<caption>Germany</caption>
+<tr><th>256GB 8GB RAM</th></tr>
+<tr><td>MediaMarkt</td><td>€ 899.00</td></tr>
+</table>
+<table class="specs"><tr><td>Price</td><td>$ 1.00</td></tr></table>
+</div>
+</body></html>"##;
+    
+    fn offers(html: &str) -> Vec<(String, String, String, f64, Option<String>)> {
+        parse_price_page(html, 12773, "2024-03-01T10:00:00-03:00").into_iter()
+            .map(|o| (o.region, o.store, o.currency, o.amount, o.variant))
+            .collect()
+    }
+    
+    #[test]
+    fn offers_per_region_store_and_variant() {
+        let found = offers(PRICE_PAGE);
+        let s = |text: &str| text.to_string();
+        
+        assert_eq!(found, [
+            (s("United States"), s("Amazon"), s("USD"), 799.99, Some(s("128GB 8GB RAM"))),
+            (s("United States"), s("Amazon"), s("USD"), 859.99, Some(s("256GB 8GB RAM"))),
+            (s("United States"), s("Best Buy"), s("USD"), 799.99, Some(s("128GB 8GB RAM"))),
+            // Cabeçalho sem a coluna da loja: as variantes começam na primeira <th>
+            (s("Germany"), s("MediaMarkt"), s("EUR"), 899.0, Some(s("256GB 8GB RAM"))),
+        ]);
+    }
+    
+    #[test]
+    fn observation_carries_device_and_timestamp() {
+        let observation = &parse_price_page(PRICE_PAGE, 12773, "2024-03-01T10:00:00-03:00")[0];
+        assert_eq!(observation.device_id, 12773);
+        assert_eq!(observation.observed_at, "2024-03-01T10:00:00-03:00");
+        
+        assert!(parse_price_page("<html><body>No prices yet</body></html>", 1, "").is_empty());
+    }
+}
@@ -0,0 +1,112 @@
+use select::document::Document;
+use select::node::Node;
+use select::predicate::{Class, Name};
+
+use crate::models::{RankingEntry, RankingList};
+use crate::utils;
+
+// Módulos da barra lateral da home: "Top 10 by daily interest" e "Top 10 by fans"
+pub fn parse_rankings(html: &str, base_url: &str, captured_at: &str) -> Vec<RankingEntry> {
+    let document = Document::from(html);
+    let snapshot_date = captured_at.get(..10).unwrap_or(captured_at).to_string();
+    let mut entries = Vec::new();
+    
+    for module in document.find(Class("module-rankings")) {
+        let heading = module.find(Name("h4"))
+            .next()
+            .map(|h| h.text().to_lowercase())
+            .unwrap_or_default();
+        
+        let list = if heading.contains("daily interest") {
+            RankingList::DailyInterest
+        } else if heading.contains("fans") {
+            RankingList::Fans
+        } else {
+            continue;
+        };
+        
+        // Posição de reserva conta só as linhas com aparelho (o cabeçalho também é um <tr>)
+        let first_entry = entries.len();
+        
+        for tr in module.find(Name("tr")) {
+            let Some(link) = tr.find(Name("a")).next() else {
+                continue;
+            };
+            let Some(href) = link.attr("href") else {
+                continue;
+            };
+            let url = format!("{}/{}", base_url, href.trim_start_matches('/'));
+            let Some(device_id) = utils::device_id_from_url(&url) else {
+                continue;
+            };
+            
+            let cells: Vec<Node> = tr.find(Name("td")).collect();
+            // Primeira célula "1.", última com hits/fãs ("27,931")
+            let rank = cells.first()
+                .and_then(|td| td.text().trim().trim_end_matches('.').parse().ok())
+                .unwrap_or(entries.len() - first_entry + 1);
+            let value = cells.last()
+                .and_then(|td| td.text().trim().replace(',', "").parse().ok());
+            
+            entries.push(RankingEntry {
+                snapshot_date: snapshot_date.clone(),
+                captured_at: captured_at.to_string(),
+                list,
+                rank,
+                device_id,
+                model: link.text().trim().to_string(),
+                value,
+            });
+        }
+    }
+    
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const HOME: &str = r#"<html><body>
+<div class="module module-rankings s3">
+<h4 class="section-heading">Top 10 by daily interest</h4>
+<table>
+<thead><tr><th>#</th><th>Device</th><th>Daily hits</th></tr></thead>
+<tbody>
+<tr><td>1.</td><td><a href="samsung_galaxy_s25_ultra-13322.php">Galaxy S25 Ultra</a></td><td>27,931</td></tr>
+<tr><td>2.</td><td><a href="/apple_iphone_16_pro_max-13123.php">iPhone 16 Pro Max</a></td><td>19,402</td></tr>
+</tbody>
+</table>
+</div>
+<div class="module module-rankings s3">
+<h4 class="section-heading">Top 10 by fans</h4>
+<table>
+<tr><th>Device</th><th>Favorites</th></tr>
+<tr><td><a href="samsung_galaxy_s24_ultra-12771.php">Galaxy S24 Ultra</a></td><td>1,210</td></tr>
+<tr><td><a href="xiaomi_14_ultra-12827.php">Xiaomi 14 Ultra</a></td><td>-</td></tr>
+</table>
+</div>
+<div class="module module-rankings s3">
+<h4 class="section-heading">Top 10 by something else</h4>
+<table><tr><td><a href="nokia_3310-192.php">3310</a></td></tr></table>
+</div>
+</body></html>"#;
+    
+    #[test]
+    fn daily_interest_and_fans() {
+        let entries = parse_rankings(HOME, "https://www.gsmarena.com", "2024-03-01T10:00:00-03:00");
+        let found: Vec<(RankingList, usize, u32, &str, Option<u64>)> = entries.iter()
+            .map(|e| (e.list, e.rank, e.device_id, e.model.as_str(), e.value))
+            .collect();
+        
+        assert_eq!(found, [
+            (RankingList::DailyInterest, 1, 13322, "Galaxy S25 Ultra", Some(27931)),
+            (RankingList::DailyInterest, 2, 13123, "iPhone 16 Pro Max", Some(19402)),
+            // Sem coluna de posição: a ordem das linhas com aparelho, sem contar o cabeçalho
+            (RankingList::Fans, 1, 12771, "Galaxy S24 Ultra", Some(1210)),
+            (RankingList::Fans, 2, 12827, "Xiaomi 14 Ultra", None),
+        ]);
+        assert_eq!(entries[0].snapshot_date, "2024-03-01");
+        assert_eq!(entries[0].captured_at, "2024-03-01T10:00:00-03:00");
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use regex::Regex;
+use select::document::Document;
+use select::predicate::{Class, Name};
+
+use crate::models::Review;
+use crate::specs::lab_tests::parse_hours;
+
+// Páginas da review: "modelo-review-2664.php", "modelo-review-2664p2.php", ...
+pub const PAGE_MARKER: &str = "-review-";
+
+pub fn parse_review(device_id: u32, url: &str, pages: &[String]) -> Review {
+    let first = pages.first().map(String::as_str).unwrap_or_default();
+    let document = Document::from(first);
+    
+    let title = document.find(Class("article-info-name")).next()
+        .or_else(|| document.find(Name("h1")).next())
+        .or_else(|| document.find(Name("title")).next())
+        .map(|node| node.text().trim().to_string())
+        .unwrap_or_default();
+    
+    let text: String = pages.iter()
+        .map(|html| Document::from(html.as_str()).find(Name("body")).next().map(|b| b.text()).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+    
+    // Prós e contras ficam no veredito, normalmente a última página
+    let verdict = pages.last().map(String::as_str).unwrap_or_default();
+    
+    Review {
+        device_id,
+        url: url.to_string(),
+        title,
+        published: parse_published(&document),
+        pros: parse_list(verdict, "Pros"),
+        cons: parse_list(verdict, "Cons"),
+        battery_active_use_hours: capture(r"(?i)active use score\D{0,20}(\d+:\d{2}\s*h)", &text)
+            .and_then(|h| parse_hours(&h)),
+        battery_endurance_hours: capture(r"(?i)endurance rating\D{0,20}(\d+\s*h)", &text)
+            .and_then(|h| parse_hours(&h)),
+        display_brightness_nits: capture(r"(?i)(?:measured|max(?:imum)? brightness)[^.]{0,80}?(\d[\d,]{2,5})\s*nits", &text)
+            .or_else(|| capture(r"(?i)(\d[\d,]{2,5})\s*nits[^.]{0,40}(?:max|peak)", &text))
+            .and_then(|n| n.replace(',', "").parse().ok()),
+        page_count: pages.len(),
+    }
+}
+
+// "05 February 2024" -> "2024-02-05"
+fn parse_published(document: &Document) -> Option<String> {
+    let text = document.find(Class("dtreviewed")).next()
+        .or_else(|| document.find(Name("time")).next())
+        .map(|node| node.text())
+        .or_else(|| document.find(Name("body")).next().map(|b| b.text()))?;
+    
+    let raw = capture(r"(\d{1,2} (?:January|February|March|April|May|June|July|August|September|October|November|December) \d{4})", &text)?;
+    
+    NaiveDate::parse_from_str(&raw, "%d %B %Y")
+        .ok()
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+// Itens do primeiro <ul> depois do título "Pros"/"Cons"
+fn parse_list(html: &str, heading: &str) -> Vec<String> {
+    let pattern = format!(r"(?is)>\s*{}\s*<.*?<ul[^>]*>(.*?)</ul>", heading);
+    let Some(list) = capture(&pattern, html) else {
+        return Vec::new();
+    };
+    
+    Document::from(list.as_str())
+        .find(Name("li"))
+        .map(|li| li.text().trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn capture(pattern: &str, text: &str) -> Option<String> {
+    Regex::new(pattern)
+        .ok()?
+        .captures(text)
+        .map(|cap| cap[1].trim().to_string())
+}
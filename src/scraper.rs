@@ -1,16 +1,24 @@
 use chrono::Local;
 use reqwest::blocking::Client;
 use select::document::Document;
-use select::predicate::{Name, Class, Predicate};
+use select::predicate::{Attr, Name, Class, Predicate};
 use std::collections::HashSet;
 use std::thread;
 use std::time::{Duration, Instant};
 use regex::Regex;
 use std::fs::{File, OpenOptions};
-use std::io::Write;
+use std::io::{Read, Write};
+use flate2::read::GzDecoder;
 
-use crate::models::{Phone, PhoneDetails};
+use crate::graph;
+use crate::models::{Confidence, DeviceGraph, FieldSource, ImageAsset, Phone, PhoneDetails, PriceObservation, RankingEntry, Review};
+use crate::opinions;
+use crate::prices;
+use crate::rankings;
+use crate::review;
 use crate::config::Config;
+use crate::specs;
+use crate::utils;
 pub struct PhoneScraper {
     client: Client,
     config: Config,
@@ -67,11 +75,13 @@ impl PhoneScraper {
                     let phones_with_ids: Vec<Phone> = phones_from_page
                         .into_iter()
                         .enumerate()
-                        .map(|(i, (model, url))| Phone {
+                        .map(|(i, (model, url, thumbnail_url))| Phone {
                             id: start_id + i,
                             model,
+                            brand: utils::brand_from_url(&url),
                             url,
                             status: "pending".to_string(),
+                            thumbnail_url,
                         })
                         .collect();
                     
@@ -134,24 +144,227 @@ impl PhoneScraper {
         
         Ok(details)
     }
+
+    // Função 3: Descobrir lançamentos nas listas "Latest devices" e "In stores now"
+    pub fn discover_latest(&self, known_phones: &[Phone]) -> Result<Vec<Phone>, Box<dyn std::error::Error>> {
+        println!("🔎 Buscando lançamentos na página inicial...");
+
+        let response = self.client.get(&self.config.base_url).send()?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()).into());
+        }
+
+        let body = response.text()?;
+        let document = Document::from(body.as_str());
+        let latest = self.extract_latest_devices(&document);
+        println!("   📋 {} aparelhos nas listas de lançamentos", latest.len());
+
+        let new_phones = self.new_latest_phones(latest, known_phones);
+
+        println!("✅ {} aparelhos novos encontrados", new_phones.len());
+        Ok(new_phones)
+    }
+
+    // Descarta os que já estão no dataset ou aparecem nas duas listas; IDs continuam a numeração
+    fn new_latest_phones(&self, latest: Vec<(String, String)>, known_phones: &[Phone]) -> Vec<Phone> {
+        let mut known_ids: HashSet<u32> = known_phones.iter()
+            .filter_map(|phone| phone.device_id())
+            .collect();
+        let mut next_id = known_phones.iter().map(|phone| phone.id).max().unwrap_or(0) + 1;
+        let mut new_phones = Vec::new();
+
+        for (model, url) in latest {
+            let Some(device_id) = utils::device_id_from_url(&url) else {
+                continue;
+            };
+
+            // insert() retorna false se o ID já existe (no dataset ou repetido entre as listas)
+            if !self.is_smartphone(&model) || !known_ids.insert(device_id) {
+                continue;
+            }
+
+            println!("   🆕 {} ({})", model, url);
+            new_phones.push(Phone {
+                id: next_id,
+                model,
+                brand: utils::brand_from_url(&url),
+                url,
+                status: "pending".to_string(),
+                thumbnail_url: None,
+            });
+            next_id += 1;
+        }
+
+        new_phones
+    }
+
+    // Função 4: Enumerar o catálogo completo a partir do(s) sitemap(s) XML
+    pub fn scrape_sitemap_urls(&self) -> Result<Vec<Phone>, Box<dyn std::error::Error>> {
+        println!("🗺️  Enumerando catálogo via sitemap: {}", self.config.sitemap_url);
+        
+        let mut pending_sitemaps = vec![self.config.sitemap_url.clone()];
+        let mut visited_sitemaps = HashSet::new();
+        let mut seen_ids = HashSet::new();
+        let mut all_phones = Vec::new();
+        
+        while let Some(sitemap_url) = pending_sitemaps.pop() {
+            if !visited_sitemaps.insert(sitemap_url.clone()) {
+                continue;
+            }
+            
+            println!("📄 Sitemap: {}", sitemap_url);
+            
+            let xml = match self.fetch_sitemap(&sitemap_url) {
+                Ok(xml) => xml,
+                Err(e) => {
+                    println!("   ❌ Erro: {}", e);
+                    continue;
+                }
+            };
+            
+            let locations = self.extract_sitemap_locations(&xml);
+            
+            // Sitemap index: cada <loc> aponta para outro sitemap
+            if xml.contains("<sitemapindex") {
+                println!("   📚 Índice com {} sitemaps", locations.len());
+                pending_sitemaps.extend(locations);
+                self.random_delay();
+                continue;
+            }
+            
+            let phones = self.sitemap_phones(locations, &mut seen_ids);
+            println!("   ✅ {} aparelhos encontrados", phones.len());
+            all_phones.extend(phones);
+            
+            if !pending_sitemaps.is_empty() {
+                self.random_delay();
+            }
+        }
+        
+        // Ordenar por ID do GSMArena e reatribuir IDs sequenciais
+        all_phones.sort_by_key(|phone| phone.device_id());
+        for (i, phone) in all_phones.iter_mut().enumerate() {
+            phone.id = i + 1;
+        }
+        
+        println!("✅ Total de aparelhos no catálogo: {}", all_phones.len());
+        Ok(all_phones)
+    }
     
     // Métodos auxiliares privados
     
+    fn fetch_sitemap(&self, url: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let response = self.client.get(url).send()?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()).into());
+        }
+        
+        let bytes = response.bytes()?;
+        self.decode_sitemap(&bytes)
+    }
     
-    fn extract_phones_from_page(&self, document: &Document) -> Vec<(String, String)> {
+    // Sitemaps .xml.gz: detectar pelo cabeçalho gzip (0x1f 0x8b)
+    fn decode_sitemap(&self, bytes: &[u8]) -> Result<String, Box<dyn std::error::Error>> {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut xml = String::new();
+            GzDecoder::new(bytes).read_to_string(&mut xml)?;
+            Ok(xml)
+        } else {
+            Ok(String::from_utf8_lossy(bytes).into_owned())
+        }
+    }
+    
+    // Só páginas de especificações; seen_ids evita repetir aparelhos entre sitemaps
+    fn sitemap_phones(&self, locations: Vec<String>, seen_ids: &mut HashSet<u32>) -> Vec<Phone> {
+        let mut phones = Vec::new();
+        
+        for url in locations {
+            if !utils::is_device_spec_url(&url) {
+                continue;
+            }
+            
+            let Some(device_id) = utils::device_id_from_url(&url) else {
+                continue;
+            };
+            
+            let model = self.extract_phone_name_from_url(&url);
+            if !self.is_smartphone(&model) || !seen_ids.insert(device_id) {
+                continue;
+            }
+            
+            phones.push(Phone {
+                id: 0,
+                model,
+                brand: utils::brand_from_url(&url),
+                url,
+                status: "pending".to_string(),
+                thumbnail_url: None,
+            });
+        }
+        
+        phones
+    }
+    
+    fn extract_sitemap_locations(&self, xml: &str) -> Vec<String> {
+        let Ok(re) = Regex::new(r"<loc>\s*(?:<!\[CDATA\[)?\s*([^<\]]+?)\s*(?:\]\]>)?\s*</loc>") else {
+            return Vec::new();
+        };
+        
+        re.captures_iter(xml)
+            .map(|cap| cap[1].replace("&amp;", "&"))
+            .collect()
+    }
+
+    fn extract_latest_devices(&self, document: &Document) -> Vec<(String, String)> {
+        let mut phones = Vec::new();
+
+        for module in document.find(Class("module-phones")) {
+            let heading = module.find(Name("h4"))
+                .next()
+                .map(|h| h.text().to_lowercase())
+                .unwrap_or_default();
+
+            if !heading.contains("latest devices") && !heading.contains("in stores now") {
+                continue;
+            }
+
+            for node in module.find(Name("a")) {
+                if let Some(href) = node.attr("href")
+                    && href.ends_with(".php") && !href.contains("review") && !href.contains("#") {
+                    let full_url = format!("{}/{}", self.config.base_url, href.trim_start_matches('/'));
+                    let text = node.text().trim().to_string();
+                    let phone_name = if text.is_empty() {
+                        self.extract_phone_name_from_url(href)
+                    } else {
+                        text
+                    };
+
+                    phones.push((phone_name, full_url));
+                }
+            }
+        }
+
+        phones
+    }
+    
+    
+    fn extract_phones_from_page(&self, document: &Document) -> Vec<(String, String, Option<String>)> {
         let mut phones = Vec::new();
         
         for node in document.find(Class("makers").descendant(Name("a"))) {
-            if let Some(href) = node.attr("href") {
-                if href.ends_with(".php") && !href.contains("review") && !href.contains("#") {
+            if let Some(href) = node.attr("href")
+                && href.ends_with(".php") && !href.contains("review") && !href.contains("#") {
                     let full_url = format!("{}/{}", self.config.base_url, href);
                     let phone_name = self.extract_phone_name_from_node(&node);
+                    let thumbnail_url = node.find(Name("img"))
+                        .next()
+                        .and_then(|img| img.attr("src"))
+                        .map(|src| self.absolute_url(src));
                     
                     if self.is_smartphone(&phone_name) {
-                        phones.push((phone_name, full_url));
+                        phones.push((phone_name, full_url, thumbnail_url));
                     }
                 }
-            }
         }
         
         phones
@@ -170,13 +383,16 @@ impl PhoneScraper {
     }
     
     fn extract_phone_name_from_url(&self, href: &str) -> String {
-        let filename = href.split('/').last().unwrap_or(href);
+        let filename = href.split('/').next_back().unwrap_or(href);
         let without_ext = filename.trim_end_matches(".php");
         let model_part = without_ext.split('-').next().unwrap_or("");
         
+        // Pular as palavras da marca no slug (ex: "sony_ericsson", "t_mobile" ocupam duas)
+        let brand_words = utils::brand_slug_words(href);
+        
         model_part
             .split('_')
-            .skip(1)
+            .skip(brand_words)
             .map(|word| {
                 let mut chars: Vec<char> = word.chars().collect();
                 if !chars.is_empty() {
@@ -197,8 +413,6 @@ impl PhoneScraper {
         "watch", "gear", "wear",          // Smartwatches
         "active", "xcover", "rugged",     // Rugged/enterprise
         "buds", "level", "icon",          // Acessórios
-        "galaxy z fold",                  // Dobráveis (opcional)
-        "galaxy z flip",                  // Flip (opcional)
         "galaxy note",                    // Notes (alguns são antigos)
     ];
     
@@ -211,7 +425,7 @@ impl PhoneScraper {
     
     // Incluir apenas modelos modernos (opcional)
     let include_patterns = [
-        "galaxy s", "galaxy a", "galaxy m", "galaxy f", "galaxy j"
+        "galaxy s", "galaxy a", "galaxy m", "galaxy f", "galaxy j", "galaxy z"
     ];
     
     // Verificar se é um modelo de smartphone reconhecido
@@ -228,7 +442,7 @@ impl PhoneScraper {
     let is_old_model = exclude_old_models.iter().any(|model| lower.contains(model));
     
     // Critérios finais
-    name.trim().len() > 0 &&
+    !name.trim().is_empty() &&
     name != "Desconhecido" &&
     !is_old_model &&
     (is_known_model || !lower.contains("galaxy")) // Se não for Galaxy, aceitar se passar outros filtros
@@ -251,7 +465,7 @@ impl PhoneScraper {
         false
     }
     
-    fn scrape_single_phone_details(&self, phone: &Phone, current: usize, total: usize) -> PhoneDetails {
+    fn scrape_single_phone_details(&self, phone: &Phone, _current: usize, _total: usize) -> PhoneDetails {
         let mut details = PhoneDetails::new(phone);
         
         println!("   📍 URL: {}", phone.url);
@@ -259,11 +473,17 @@ impl PhoneScraper {
         match self.client.get(&phone.url).send() {
             Ok(response) => {
                 details.status_code = response.status().as_u16();
+                details.fetched_at = Some(chrono::Local::now().to_rfc3339());
+                details.record_provenance("fetched_at", FieldSource::HttpFetch { url: phone.url.clone() }, Confidence::High);
                 
                 if response.status().is_success() {
                     match response.text() {
                         Ok(html) => {
                             self.extract_display_info(&html, &mut details);
+                            specs::extract_all(&html, &mut details);
+                            if self.config.download_images {
+                                self.download_images(phone, &html, &mut details);
+                            }
                             println!("   ✅ Dados extraídos");
                         }
                         Err(e) => {
@@ -297,33 +517,36 @@ impl PhoneScraper {
             if cells.len() >= 2 {
                 let label = cells[0].to_lowercase();
                 let value = cells[1].clone();
+                let source = FieldSource::TableRow { section: "Display".to_string(), label: cells[0].clone() };
                 
                 match label.as_str() {
                     l if l.contains("size") => {
                         details.screen_size = Some(self.extract_screen_size(&value));
+                        details.record_provenance("screen_size", source, Confidence::High);
                         
                         // Calcular área se tivermos ratio
-                        if let (Some(size), Some(ratio)) = (&details.screen_size, &details.display_ratio) {
-                            if let Some(area) = self.calculate_display_area(size, ratio) {
-                                details.display_area_cm2 = Some(format!("{:.2}", area));
-                            }
+                        if let (Some(size), Some(ratio)) = (&details.screen_size, &details.display_ratio)
+                            && let Some(area) = utils::calculate_display_area(size, ratio) {
+                            details.display_area_cm2 = Some(format!("{:.2}", area));
                         }
                     }
                     l if l.contains("resolution") => {
                         details.resolution = Some(self.extract_resolution(&value));
+                        details.record_provenance("resolution", source, Confidence::High);
                     }
                     l if l.contains("ratio") => {
                         details.display_ratio = Some(self.extract_ratio(&value));
+                        details.record_provenance("display_ratio", source, Confidence::High);
                         
                         // Calcular área se tivermos tamanho
-                        if let (Some(size), Some(ratio)) = (&details.screen_size, &details.display_ratio) {
-                            if let Some(area) = self.calculate_display_area(size, ratio) {
-                                details.display_area_cm2 = Some(format!("{:.2}", area));
-                            }
+                        if let (Some(size), Some(ratio)) = (&details.screen_size, &details.display_ratio)
+                            && let Some(area) = utils::calculate_display_area(size, ratio) {
+                            details.display_area_cm2 = Some(format!("{:.2}", area));
                         }
                     }
                     l if l.contains("ppi") || l.contains("pixel density") => {
                         details.ppi = Some(self.extract_ppi(&value));
+                        details.record_provenance("ppi", source, Confidence::High);
                     }
                     _ => {}
                 }
@@ -334,34 +557,45 @@ impl PhoneScraper {
         if details.screen_size.is_none() || details.display_ratio.is_none() {
             self.extract_with_regex(html, details);
         }
+        
+        if details.display_area_cm2.is_some() {
+            let confidence = details.derived_confidence(&["screen_size", "display_ratio"]);
+            let source = FieldSource::Derived { from: vec!["screen_size".to_string(), "display_ratio".to_string()] };
+            details.record_provenance("display_area_cm2", source, confidence);
+        }
     }
     
     fn extract_with_regex(&self, html: &str, details: &mut PhoneDetails) {
         let html_lower = html.to_lowercase();
+        let size_pattern = r#"(\d+\.?\d*)\s*(?:inches|"|inch)"#;
+        let ratio_pattern = r"(\d+\.?\d*\s*[:]\s*\d+\.?\d*)";
+        let resolution_pattern = r"(\d+\s*x\s*\d+)";
         
-        if details.screen_size.is_none() {
-            if let Some(size) = self.find_pattern(&html_lower, r#"(\d+\.?\d*)\s*(?:inches|"|inch)"#) {
-                details.screen_size = Some(format!("{}\"", size));
-            }
+        // Regex sobre a página inteira: pode pegar banners/anúncios, confiança baixa
+        let fallback = |pattern: &str| FieldSource::FallbackRegex { pattern: pattern.to_string() };
+        
+        if details.screen_size.is_none()
+            && let Some(size) = self.find_pattern(&html_lower, size_pattern) {
+            details.screen_size = Some(format!("{}\"", size));
+            details.record_provenance("screen_size", fallback(size_pattern), Confidence::Low);
         }
         
-        if details.display_ratio.is_none() {
-            if let Some(ratio) = self.find_pattern(&html_lower, r"(\d+\.?\d*\s*[:]\s*\d+\.?\d*)") {
-                details.display_ratio = Some(ratio.replace(" ", ""));
-            }
+        if details.display_ratio.is_none()
+            && let Some(ratio) = self.find_pattern(&html_lower, ratio_pattern) {
+            details.display_ratio = Some(ratio.replace(" ", ""));
+            details.record_provenance("display_ratio", fallback(ratio_pattern), Confidence::Low);
         }
         
-        if details.resolution.is_none() {
-            if let Some(res) = self.find_pattern(&html_lower, r"(\d+\s*x\s*\d+)") {
-                details.resolution = Some(res.replace(" ", ""));
-            }
+        if details.resolution.is_none()
+            && let Some(res) = self.find_pattern(&html_lower, resolution_pattern) {
+            details.resolution = Some(res.replace(" ", ""));
+            details.record_provenance("resolution", fallback(resolution_pattern), Confidence::Low);
         }
         
         // Calcular área se agora temos ambos
-        if let (Some(size), Some(ratio)) = (&details.screen_size, &details.display_ratio) {
-            if let Some(area) = self.calculate_display_area(size, ratio) {
-                details.display_area_cm2 = Some(format!("{:.2}", area));
-            }
+        if let (Some(size), Some(ratio)) = (&details.screen_size, &details.display_ratio)
+            && let Some(area) = utils::calculate_display_area(size, ratio) {
+            details.display_area_cm2 = Some(format!("{:.2}", area));
         }
     }
     
@@ -405,26 +639,6 @@ fn extract_ppi(&self, text: &str) -> String {
             .map(|cap| cap[1].to_string())
     }
     
-    fn calculate_display_area(&self, size_text: &str, ratio_text: &str) -> Option<f64> {
-        let size_re = Regex::new(r"(\d+\.?\d*)").ok()?;
-        let size_cap = size_re.captures(size_text)?;
-        let diagonal_inches: f64 = size_cap[1].parse().ok()?;
-        
-        let ratio_re = Regex::new(r"(\d+\.?\d*)\s*[:]\s*(\d+\.?\d*)").ok()?;
-        let ratio_cap = ratio_re.captures(ratio_text)?;
-        
-        let a: f64 = ratio_cap[1].parse().ok()?;
-        let b: f64 = ratio_cap[2].parse().ok()?;
-        
-        let ratio = b / a;
-        let width_inches = diagonal_inches / (1.0 + ratio * ratio).sqrt();
-        let height_inches = width_inches * ratio;
-        
-        let width_cm = width_inches * 2.54;
-        let height_cm = height_inches * 2.54;
-        
-        Some(width_cm * height_cm)
-    }
     
   
         pub fn scrape_phone_details_with_batching(&self, phones: &[Phone]) -> Result<Vec<PhoneDetails>, Box<dyn std::error::Error>> {
@@ -437,7 +651,7 @@ fn extract_ppi(&self, text: &str) -> String {
         );
         
         let mut all_details = Vec::new();
-        let total_batches = (phones.len() + self.config.batch_size - 1) / self.config.batch_size;
+        let total_batches = phones.len().div_ceil(self.config.batch_size);
         
         // Verificar se já existe progresso salvo
         let (start_batch, processed_count) = self.load_progress()?;
@@ -522,11 +736,17 @@ fn extract_ppi(&self, text: &str) -> String {
             match self.client.get(&phone.url).send() {
                 Ok(response) => {
                     detail.status_code = response.status().as_u16();
+                    detail.fetched_at = Some(chrono::Local::now().to_rfc3339());
+                    detail.record_provenance("fetched_at", FieldSource::HttpFetch { url: phone.url.clone() }, Confidence::High);
                     
                     if response.status().is_success() {
                         match response.text() {
                             Ok(html) => {
                                 self.extract_display_info(&html, &mut detail);
+                                specs::extract_all(&html, &mut detail);
+                                if self.config.download_images {
+                                    self.download_images(phone, &html, &mut detail);
+                                }
                                 if detail.has_display_info() {
                                     println!("   ✅ Dados extraídos (tentativa {})", attempt);
                                 } else {
@@ -574,6 +794,325 @@ fn extract_ppi(&self, text: &str) -> String {
         Ok(detail)
     }
     
+    // Reviews editoriais: página de specs -> link da review -> todas as páginas
+    pub fn scrape_reviews(&self, phones: &[Phone]) -> Result<Vec<Review>, Box<dyn std::error::Error>> {
+        println!("📰 Buscando reviews de {} aparelhos...", phones.len());
+        let mut reviews = Vec::new();
+        
+        for phone in phones {
+            let Some(device_id) = phone.device_id() else {
+                continue;
+            };
+            
+            let html = match self.client.get(&phone.url).send().and_then(|r| r.error_for_status()).and_then(|r| r.text()) {
+                Ok(html) => html,
+                Err(e) => {
+                    println!("   ❌ {}: {}", phone.model, e);
+                    self.random_delay();
+                    continue;
+                }
+            };
+            let Some(href) = specs::header::find_review_url(&Document::from(html.as_str())) else {
+                self.random_delay();
+                continue;
+            };
+            
+            let review_url = self.absolute_url(&href);
+            println!("   📰 {} -> {}", phone.model, review_url);
+            
+            match self.fetch_review_pages(&review_url) {
+                Ok(pages) => reviews.push(review::parse_review(device_id, &review_url, &pages)),
+                Err(e) => println!("   ❌ Review não coletada: {}", e),
+            }
+            
+            self.random_delay();
+        }
+        
+        println!("✅ {} reviews coletadas", reviews.len());
+        Ok(reviews)
+    }
+    
+    fn fetch_review_pages(&self, review_url: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let first = self.client.get(review_url).send()?.error_for_status()?.text()?;
+        let total = utils::page_count(&first, review::PAGE_MARKER);
+        let mut pages = vec![first];
+        
+        for page in 2..=total {
+            self.random_delay();
+            let url = utils::paged_url(review_url, page);
+            pages.push(self.client.get(&url).send()?.error_for_status()?.text()?);
+        }
+        
+        Ok(pages)
+    }
+    
+    // Opiniões de usuários, paginadas, gravadas em JSONL (uma opinião por linha)
+    pub fn scrape_opinions(&self, phones: &[Phone], max_pages: Option<usize>, filename: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        println!("💬 Coletando opiniões de {} aparelhos...", phones.len());
+        let file = OpenOptions::new().create(true).append(true).open(filename)?;
+        let mut writer = std::io::BufWriter::new(file);
+        let mut written = 0;
+        
+        for phone in phones {
+            let (Some(device_id), Some(first_url)) = (phone.device_id(), opinions::opinions_url(&phone.url)) else {
+                continue;
+            };
+            
+            let mut page = 1;
+            let mut total_pages = 1;
+            let mut pages_read = 0;
+            
+            while page <= total_pages {
+                let url = utils::paged_url(&first_url, page);
+                let html = match self.client.get(&url).send().and_then(|r| r.error_for_status()).and_then(|r| r.text()) {
+                    Ok(html) => html,
+                    Err(e) => {
+                        println!("   ❌ {} (página {}): {}", phone.model, page, e);
+                        self.random_delay();
+                        break;
+                    }
+                };
+                
+                if page == 1 {
+                    total_pages = utils::page_count(&html, opinions::PAGE_MARKER);
+                    if let Some(cap) = max_pages {
+                        total_pages = total_pages.min(cap);
+                    }
+                }
+                
+                for opinion in opinions::parse_opinions(&html, device_id, page) {
+                    serde_json::to_writer(&mut writer, &opinion)?;
+                    writeln!(writer)?;
+                    written += 1;
+                }
+                
+                pages_read += 1;
+                page += 1;
+                self.random_delay();
+            }
+            
+            // Sem a primeira página não há como saber o total: aparelho pulado
+            if pages_read == 0 {
+                println!("   ⏭️  {}: opiniões não coletadas", phone.model);
+                continue;
+            }
+            
+            writer.flush()?;
+            println!("   💬 {}: {} de {} página(s)", phone.model, pages_read, total_pages);
+        }
+        
+        println!("✅ {} opiniões salvas em: {}", written, filename);
+        Ok(written)
+    }
+    
+    // Rankings da home (Top 10 por interesse diário e por fãs)
+    pub fn scrape_rankings(&self) -> Result<Vec<RankingEntry>, Box<dyn std::error::Error>> {
+        println!("🏆 Capturando rankings da página inicial...");
+        let html = self.client.get(&self.config.base_url).send()?.error_for_status()?.text()?;
+        let entries = rankings::parse_rankings(&html, &self.config.base_url, &Local::now().to_rfc3339());
+        
+        if entries.is_empty() {
+            return Err("Nenhum ranking encontrado na página inicial".into());
+        }
+        
+        Ok(entries)
+    }
+    
+    // Snapshot a cada `interval_hours`; runs = None roda até o processo ser interrompido
+    pub fn schedule_rankings(&self, interval_hours: u64, runs: Option<usize>, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mut completed = 0;
+        
+        loop {
+            match self.scrape_rankings() {
+                Ok(entries) => utils::append_rankings(&entries, filename)?,
+                Err(e) => println!("   ❌ Snapshot falhou: {}", e),
+            }
+            completed += 1;
+            
+            if runs.is_some_and(|runs| completed >= runs) {
+                return Ok(());
+            }
+            
+            println!("⏰ Próximo snapshot em {}h", interval_hours);
+            thread::sleep(Duration::from_secs(interval_hours * 3600));
+        }
+    }
+    
+    // Ofertas das lojas (página "-price-") com timestamp da coleta
+    pub fn scrape_price_history(&self, phones: &[Phone]) -> Result<Vec<PriceObservation>, Box<dyn std::error::Error>> {
+        println!("💲 Coletando preços de {} aparelhos...", phones.len());
+        let mut observations = Vec::new();
+        
+        for phone in phones {
+            let (Some(device_id), Some(url)) = (phone.device_id(), utils::device_subpage_url(&phone.url, "price")) else {
+                continue;
+            };
+            
+            match self.client.get(&url).send().and_then(|r| r.error_for_status()).and_then(|r| r.text()) {
+                Ok(html) => {
+                    let observed_at = Local::now().to_rfc3339();
+                    let found = prices::parse_price_page(&html, device_id, &observed_at);
+                    println!("   💲 {}: {} ofertas", phone.model, found.len());
+                    observations.extend(found);
+                }
+                Err(e) => println!("   ❌ {}: {}", phone.model, e),
+            }
+            
+            self.random_delay();
+        }
+        
+        Ok(observations)
+    }
+    
+    // Busca em largura pelos links "Related devices"/"Compare"; budget limita as páginas baixadas
+    pub fn crawl_related(&self, seeds: &[Phone], max_depth: usize, budget: usize) -> Result<DeviceGraph, Box<dyn std::error::Error>> {
+        println!("🕸️  Rastreando relacionados (profundidade {}, até {} páginas)...", max_depth, budget);
+        
+        let (graph, attempts) = graph::crawl(seeds, &self.config.base_url, max_depth, budget, |url| {
+            let result = self.client.get(url).send().and_then(|r| r.error_for_status()).and_then(|r| r.text());
+            self.random_delay();
+            
+            match result {
+                Ok(html) => Some(html),
+                Err(e) => {
+                    println!("   ❌ {}: {}", url, e);
+                    None
+                }
+            }
+        });
+        
+        if attempts >= budget {
+            println!("   ⏹️  Orçamento de {} páginas atingido", budget);
+        }
+        println!("✅ {} nós, {} arestas ({} páginas)", graph.nodes.len(), graph.edges.len(), attempts);
+        Ok(graph)
+    }
+    
+    // Aparelhos do grafo que ainda não estão na lista conhecida viram novas entradas
+    pub fn phones_from_graph(&self, graph: &DeviceGraph, known_phones: &[Phone]) -> Vec<Phone> {
+        let known_ids: HashSet<u32> = known_phones.iter()
+            .filter_map(|phone| phone.device_id())
+            .collect();
+        let mut next_id = known_phones.iter().map(|phone| phone.id).max().unwrap_or(0) + 1;
+        let mut new_phones = Vec::new();
+        
+        for node in graph.nodes.values() {
+            let Some(url) = &node.url else {
+                continue;
+            };
+            if known_ids.contains(&node.id) {
+                continue;
+            }
+            
+            let model = node.model.clone().unwrap_or_else(|| self.extract_phone_name_from_url(url));
+            if !self.is_smartphone(&model) {
+                continue;
+            }
+            
+            new_phones.push(Phone {
+                id: next_id,
+                model,
+                brand: utils::brand_from_url(url),
+                url: url.clone(),
+                status: "pending".to_string(),
+                thumbnail_url: None,
+            });
+            next_id += 1;
+        }
+        
+        new_phones
+    }
+    
+    // Miniatura da listagem + galeria da página de fotos; falhas não invalidam os dados
+    fn download_images(&self, phone: &Phone, html: &str, detail: &mut PhoneDetails) {
+        if let Some(url) = &phone.thumbnail_url {
+            self.random_delay();
+            match self.fetch_image(url) {
+                Ok(asset) => {
+                    detail.thumbnail = Some(asset);
+                    detail.record_provenance("thumbnail", FieldSource::HttpFetch { url: url.clone() }, Confidence::High);
+                }
+                Err(e) => println!("   ⚠️  Miniatura não baixada: {}", e),
+            }
+        }
+        
+        let Some(pictures_url) = self.find_pictures_url(html, &phone.url) else {
+            return;
+        };
+        
+        self.random_delay();
+        let page = match self.client.get(&pictures_url).send().and_then(|r| r.error_for_status()).and_then(|r| r.text()) {
+            Ok(page) => page,
+            Err(e) => {
+                println!("   ⚠️  Galeria indisponível: {}", e);
+                return;
+            }
+        };
+        
+        // Galerias podem ter dezenas de fotos; limita por aparelho
+        for url in self.extract_picture_urls(&page).into_iter().take(self.config.max_gallery_images) {
+            self.random_delay();
+            match self.fetch_image(&url) {
+                Ok(asset) => detail.pictures.push(asset),
+                Err(e) => println!("   ⚠️  Foto não baixada ({}): {}", url, e),
+            }
+        }
+        
+        if !detail.pictures.is_empty() {
+            detail.record_provenance("pictures", FieldSource::HttpFetch { url: pictures_url }, Confidence::High);
+        }
+        
+        println!("   🖼️  {} fotos na galeria", detail.pictures.len());
+    }
+    
+    fn fetch_image(&self, url: &str) -> Result<ImageAsset, Box<dyn std::error::Error>> {
+        let response = self.client.get(url).send()?.error_for_status()?;
+        let bytes = response.bytes()?;
+        
+        utils::store_image(&bytes, url, &self.config.image_store_dir)
+    }
+    
+    // Link "Pictures" da página de specs; sem ele, deriva de modelo-ID.php -> modelo-pictures-ID.php
+    fn find_pictures_url(&self, html: &str, phone_url: &str) -> Option<String> {
+        let document = Document::from(html);
+        let href = document.find(Name("a"))
+            .filter_map(|a| a.attr("href"))
+            .find(|href| href.contains("-pictures-"));
+        
+        if let Some(href) = href {
+            return Some(self.absolute_url(href));
+        }
+        
+        utils::device_subpage_url(phone_url, "pictures")
+    }
+    
+    fn extract_picture_urls(&self, html: &str) -> Vec<String> {
+        let document = Document::from(html);
+        let mut urls: Vec<String> = Vec::new();
+        
+        for img in document.find(Attr("id", "pictures-list").descendant(Name("img"))) {
+            // Imagens com lazy-load trazem a URL real em data-src
+            if let Some(src) = img.attr("data-src").or_else(|| img.attr("src")) {
+                let url = self.absolute_url(src);
+                if !urls.contains(&url) {
+                    urls.push(url);
+                }
+            }
+        }
+        
+        urls
+    }
+    
+    fn absolute_url(&self, href: &str) -> String {
+        if href.starts_with("http") {
+            href.to_string()
+        } else if let Some(rest) = href.strip_prefix("//") {
+            format!("https://{}", rest)
+        } else {
+            format!("{}/{}", self.config.base_url, href.trim_start_matches('/'))
+        }
+    }
+    
     fn load_progress(&self) -> Result<(usize, usize), Box<dyn std::error::Error>> {
         let progress_file = "scraper_progress.txt";
         
@@ -656,14 +1195,12 @@ fn extract_ppi(&self, text: &str) -> String {
         writeln!(log_file, "Total de telefones: {}", total)?;
         writeln!(log_file, "{}", "=".repeat(60))?;
         
-        if std::path::Path::new(progress_file).exists() {
-            if let Ok(progress) = std::fs::read_to_string(progress_file) {
-                if let Ok(last_index) = progress.trim().parse::<usize>() {
-                    start_index = last_index;
-                    println!("🔄 Continuando do telefone {} de {}", start_index + 1, total);
-                    writeln!(log_file, "🔄 Retomando do telefone {} de {}", start_index + 1, total)?;
-                }
-            }
+        if std::path::Path::new(progress_file).exists()
+            && let Ok(progress) = std::fs::read_to_string(progress_file)
+            && let Ok(last_index) = progress.trim().parse::<usize>() {
+            start_index = last_index;
+            println!("🔄 Continuando do telefone {} de {}", start_index + 1, total);
+            writeln!(log_file, "🔄 Retomando do telefone {} de {}", start_index + 1, total)?;
         }
         
         let mut successful_count = 0;
@@ -779,4 +1316,132 @@ fn extract_ppi(&self, text: &str) -> String {
         println!("\r   ✅ Próximo processamento!                    ");
     }
    
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn scraper() -> PhoneScraper {
+        PhoneScraper::new(Config::new()).unwrap()
+    }
+    
+    fn phone(id: usize, url: &str) -> Phone {
+        Phone {
+            id,
+            model: String::new(),
+            url: url.to_string(),
+            status: "done".to_string(),
+            brand: None,
+            thumbnail_url: None,
+        }
+    }
+    
+    const HOME: &str = r#"<html><body>
+<div class="module module-phones module-latest">
+<h4 class="section-heading">Latest devices</h4>
+<div class="module-phones-link"><a href="samsung_galaxy_a36-13497.php"><img src="a36.jpg">Galaxy A36</a></div>
+<div class="module-phones-link"><a href="xiaomi_15-13461.php"><img src="15.jpg">Xiaomi 15</a></div>
+<div class="module-phones-link"><a href="samsung_galaxy_tab_s10-13318.php"><img src="tab.jpg">Galaxy Tab S10</a></div>
+</div>
+<div class="module module-phones module-instores">
+<h4 class="section-heading">In stores now</h4>
+<div class="module-phones-link"><a href="xiaomi_15-13461.php"><img src="15.jpg">Xiaomi 15</a></div>
+<div class="module-phones-link"><a href="apple_iphone_16-13317.php"><img src="16.jpg"></a></div>
+<div class="module-phones-link"><a href="apple_iphone_16-review-2751.php">Review</a></div>
+</div>
+<div class="module module-phones module-rankings">
+<h4 class="section-heading">Top 10 by daily interest</h4>
+<div class="module-phones-link"><a href="samsung_galaxy_a56-13603.php">Galaxy A56</a></div>
+</div>
+</body></html>"#;
+    
+    #[test]
+    fn latest_and_in_stores_lists() {
+        let latest = scraper().extract_latest_devices(&Document::from(HOME));
+        let models: Vec<&str> = latest.iter().map(|(model, _)| model.as_str()).collect();
+        
+        // Sem texto no link, o nome vem do slug; rankings e reviews ficam de fora
+        assert_eq!(models, ["Galaxy A36", "Xiaomi 15", "Galaxy Tab S10", "Xiaomi 15", "Iphone 16"]);
+        assert_eq!(latest[0].1, "https://www.gsmarena.com/samsung_galaxy_a36-13497.php");
+    }
+    
+    #[test]
+    fn latest_skips_known_and_repeated_devices() {
+        let scraper = scraper();
+        let latest = scraper.extract_latest_devices(&Document::from(HOME));
+        let known = [
+            phone(7, "https://www.gsmarena.com/samsung_galaxy_a36-13497.php"),
+            phone(3, "https://www.gsmarena.com/samsung_galaxy_s24-12773.php"),
+        ];
+        
+        let new_phones = scraper.new_latest_phones(latest, &known);
+        let found: Vec<(usize, &str)> = new_phones.iter().map(|p| (p.id, p.model.as_str())).collect();
+        
+        assert_eq!(found, [(8, "Xiaomi 15"), (9, "Iphone 16")]);
+        assert_eq!(new_phones[1].brand.as_deref(), Some("Apple"));
+    }
+    
+    #[test]
+    fn model_names_skip_multi_word_brand_slugs() {
+        let scraper = PhoneScraper::new(Config::new()).unwrap();
+        
+        assert_eq!(scraper.extract_phone_name_from_url("samsung_galaxy_s24-12773.php"), "Galaxy S24");
+        assert_eq!(scraper.extract_phone_name_from_url("t_mobile_g1-2533.php"), "G1");
+        assert_eq!(scraper.extract_phone_name_from_url("at_t_quickfire-3010.php"), "Quickfire");
+        assert_eq!(scraper.extract_phone_name_from_url("benq_siemens_ef81-1234.php"), "Ef81");
+        assert_eq!(scraper.extract_phone_name_from_url("i_mobile_i_style_8_3-5542.php"), "I Style 8 3");
+        assert_eq!(scraper.extract_phone_name_from_url("garmin_asus_nuvifone_m10-3105.php"), "Nuvifone M10");
+        assert_eq!(scraper.extract_phone_name_from_url("tel_me_t939-1470.php"), "T939");
+    }
+    
+    const SITEMAP_INDEX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+<sitemap><loc>https://www.gsmarena.com/sitemap-phones-1.xml.gz</loc></sitemap>
+<sitemap><loc><![CDATA[ https://www.gsmarena.com/sitemap-phones-2.xml.gz ]]></loc></sitemap>
+</sitemapindex>"#;
+    
+    const SITEMAP: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+<url><loc>https://www.gsmarena.com/samsung_galaxy_a55-12824.php</loc></url>
+<url><loc>https://www.gsmarena.com/samsung_galaxy_a55-reviews-12824.php</loc></url>
+<url><loc>https://www.gsmarena.com/samsung-phones-9.php</loc></url>
+<url><loc>https://www.gsmarena.com/samsung_galaxy_watch7-13187.php</loc></url>
+<url><loc>https://www.gsmarena.com/t_mobile_g1-2533.php</loc></url>
+<url><loc>https://www.gsmarena.com/samsung_galaxy_a55-12824.php</loc></url>
+</urlset>"#;
+    
+    #[test]
+    fn gzipped_sitemap_index() {
+        let scraper = scraper();
+        
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(SITEMAP_INDEX.as_bytes()).unwrap();
+        let xml = scraper.decode_sitemap(&encoder.finish().unwrap()).unwrap();
+        
+        assert!(xml.contains("<sitemapindex"));
+        assert_eq!(scraper.extract_sitemap_locations(&xml), [
+            "https://www.gsmarena.com/sitemap-phones-1.xml.gz",
+            "https://www.gsmarena.com/sitemap-phones-2.xml.gz",
+        ]);
+        
+        // XML sem compressão passa direto
+        assert_eq!(scraper.decode_sitemap(SITEMAP.as_bytes()).unwrap(), SITEMAP);
+    }
+    
+    #[test]
+    fn sitemap_keeps_new_spec_pages_only() {
+        let scraper = scraper();
+        let locations = scraper.extract_sitemap_locations(SITEMAP);
+        
+        let mut seen_ids = HashSet::new();
+        let phones = scraper.sitemap_phones(locations.clone(), &mut seen_ids);
+        let found: Vec<(&str, Option<&str>)> = phones.iter()
+            .map(|p| (p.model.as_str(), p.brand.as_deref()))
+            .collect();
+        assert_eq!(found, [("Galaxy A55", Some("Samsung")), ("G1", Some("T-Mobile"))]);
+        
+        // O mesmo aparelho em outro sitemap não volta
+        assert!(scraper.sitemap_phones(locations, &mut seen_ids).is_empty());
+    }
+}
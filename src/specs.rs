@@ -0,0 +1,459 @@
+use select::document::Document;
+use select::node::Node;
+use select::predicate::{Attr, Name};
+
+use crate::models::{CameraPosition, Confidence, DisplayRole, FieldSource, PhoneDetails};
+
+pub mod battery;
+pub mod body;
+pub mod camera;
+pub mod connectivity;
+pub mod display;
+pub mod header;
+pub mod lab_tests;
+pub mod launch;
+pub mod memory;
+pub mod misc;
+pub mod platform;
+
+// Uma linha da tabela de especificações (ex: Launch / Announced / "2021, March 17")
+#[derive(Debug, Clone)]
+pub struct SpecRow {
+    pub section: String,
+    pub label: String,
+    pub lines: Vec<String>, // Valor quebrado nos <br> da célula
+}
+
+impl SpecRow {
+    pub fn value(&self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+// Tabela de especificações com a seção de cada linha preservada
+#[derive(Debug, Clone, Default)]
+pub struct SpecSheet {
+    pub rows: Vec<SpecRow>,
+}
+
+impl SpecSheet {
+    pub fn parse(html: &str) -> Self {
+        let document = Document::from(html);
+        let mut rows = Vec::new();
+        
+        // Limitar ao bloco #specs-list quando existir (evita tabelas de anúncios)
+        let tables: Vec<Node> = match document.find(Attr("id", "specs-list")).next() {
+            Some(list) => list.find(Name("table")).collect(),
+            None => document.find(Name("table")).collect(),
+        };
+        
+        for table in tables {
+            let mut section = String::new();
+            
+            for tr in table.find(Name("tr")) {
+                // O <th> só aparece na primeira linha de cada seção (rowspan)
+                if let Some(th) = tr.find(Name("th")).next() {
+                    section = clean_text(&th.text());
+                }
+                
+                let cells: Vec<Node> = tr.find(Name("td")).collect();
+                if cells.len() < 2 || section.is_empty() {
+                    continue;
+                }
+                
+                let lines = cell_lines(&cells[1]);
+                if lines.is_empty() {
+                    continue;
+                }
+                
+                rows.push(SpecRow {
+                    section: section.clone(),
+                    label: clean_text(&cells[0].text()),
+                    lines,
+                });
+            }
+        }
+        
+        Self { rows }
+    }
+    
+    // Linhas de uma seção (comparação sem diferenciar maiúsculas)
+    pub fn section<'a>(&'a self, section: &str) -> impl Iterator<Item = &'a SpecRow> + use<'a> {
+        let section = section.to_string();
+        self.rows.iter()
+            .filter(move |row| row.section.eq_ignore_ascii_case(&section))
+    }
+    
+    pub fn get(&self, section: &str, label: &str) -> Option<&SpecRow> {
+        self.section(section)
+            .find(|row| row.label.eq_ignore_ascii_case(label))
+    }
+    
+    pub fn value(&self, section: &str, label: &str) -> Option<String> {
+        self.get(section, label).map(|row| row.value())
+    }
+}
+
+#[cfg(test)]
+impl SpecSheet {
+    // Tabela montada à mão para os testes: (seção, rótulo, valor com "\n" nas quebras)
+    pub(crate) fn from_rows(rows: &[(&str, &str, &str)]) -> Self {
+        let rows = rows.iter()
+            .map(|(section, label, value)| SpecRow {
+                section: section.to_string(),
+                label: label.to_string(),
+                lines: value.lines().map(|line| line.to_string()).collect(),
+            })
+            .collect();
+        
+        Self { rows }
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn empty_details() -> PhoneDetails {
+    let phone = crate::models::Phone {
+        id: 1,
+        model: "Test".to_string(),
+        url: "https://www.gsmarena.com/test-1.php".to_string(),
+        status: "pending".to_string(),
+        brand: None,
+        thumbnail_url: None,
+    };
+    
+    PhoneDetails::new(&phone)
+}
+
+// Extrai todas as seções conhecidas da página de especificações
+pub fn extract_all(html: &str, details: &mut PhoneDetails) {
+    let sheet = SpecSheet::parse(html);
+    
+    launch::extract(&sheet, details);
+    display::extract(&sheet, details);
+    body::extract(&sheet, details);
+    platform::extract(&sheet, details);
+    memory::extract(&sheet, details);
+    camera::extract(&sheet, details);
+    battery::extract(&sheet, details);
+    connectivity::extract(&sheet, details);
+    lab_tests::extract(&sheet, details);
+    misc::extract(&sheet, details);
+    header::extract(html, details);
+    
+    // Derivados que dependem de mais de uma seção
+    display::apply_geometry(details);
+    
+    record_provenance(&sheet, details);
+}
+
+// Origem dos campos estruturados: linha da tabela (rótulo vazio = seção inteira)
+fn record_provenance(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let connectivity = &details.connectivity;
+    let camera = &details.camera;
+    let table_fields = [
+        ("announced", "Launch", "Announced", details.announced.is_some()),
+        ("released", "Launch", launch::released_label(sheet).unwrap_or("Status"), details.released.is_some()),
+        ("lifecycle_status", "Launch", "Status", details.lifecycle_status.is_some()),
+        ("display_type", "Display", "Type", details.display_type.is_some()),
+        ("refresh_rate_hz", "Display", "Type", details.refresh_rate_hz.is_some()),
+        ("hdr_formats", "Display", "Type", !details.hdr_formats.is_empty()),
+        ("brightness_typical_nits", "Display", "Type", details.brightness_typical_nits.is_some()),
+        ("brightness_peak_nits", "Display", "Type", details.brightness_peak_nits.is_some()),
+        ("display_protection", "Display", "Protection", details.display_protection.is_some()),
+        ("body", "Body", "", !details.body.dimensions.is_empty()),
+        ("platform", "Platform", "", details.platform.os_family.is_some() || !details.platform.chipsets.is_empty()),
+        ("os", "Platform", "OS", details.os.is_some()),
+        ("chipset", "Platform", "Chipset", details.chipset.is_some()),
+        ("memory.variants", "Memory", "Internal", !details.memory.variants.is_empty()),
+        ("memory.storage_types", "Memory", "", !details.memory.storage_types.is_empty()),
+        ("memory.card_slot", "Memory", "Card slot", details.memory.card_slot.is_some()),
+        ("memory.card_slot_supported", "Memory", "Card slot", details.memory.card_slot_supported.is_some()),
+        ("ram", "Memory", "Internal", details.ram.is_some()),
+        ("storage", "Memory", "Internal", details.storage.is_some()),
+        ("camera.main_features", "Main Camera", "Features", !camera.main_features.is_empty()),
+        ("camera.main_video", "Main Camera", "Video", camera.main_video.is_some()),
+        ("camera.selfie_features", "Selfie camera", "Features", !camera.selfie_features.is_empty()),
+        ("camera.selfie_video", "Selfie camera", "Video", camera.selfie_video.is_some()),
+        ("battery", "Battery", "Type", details.battery.is_some()),
+        ("battery_specs", "Battery", "", details.battery_specs.capacity_mah.is_some()),
+        ("connectivity.network_technologies", "Network", "Technology", !connectivity.network_technologies.is_empty()),
+        ("connectivity.bands_2g", "Network", "2G bands", !connectivity.bands_2g.is_empty()),
+        ("connectivity.bands_3g", "Network", "3G bands", !connectivity.bands_3g.is_empty()),
+        ("connectivity.bands_4g", "Network", "4G bands", !connectivity.bands_4g.is_empty()),
+        ("connectivity.bands_5g", "Network", "5G bands", !connectivity.bands_5g.is_empty()),
+        ("connectivity.wlan", "Comms", "WLAN", connectivity.wlan.is_some()),
+        ("connectivity.wifi_standard", "Comms", "WLAN", connectivity.wifi_standard.is_some()),
+        ("connectivity.bluetooth_version", "Comms", "Bluetooth", connectivity.bluetooth_version.is_some()),
+        ("connectivity.positioning", "Comms", "Positioning", !connectivity.positioning.is_empty()),
+        ("connectivity.nfc", "Comms", "NFC", connectivity.nfc.is_some()),
+        ("connectivity.usb_connector", "Comms", "USB", connectivity.usb_connector.is_some()),
+        ("connectivity.usb_version", "Comms", "USB", connectivity.usb_version.is_some()),
+        ("connectivity.fm_radio", "Comms", "Radio", connectivity.fm_radio.is_some()),
+        ("connectivity.infrared", "Comms", "Infrared port", connectivity.infrared.is_some()),
+        ("connectivity.headphone_jack", "Sound", "3.5mm jack", connectivity.headphone_jack.is_some()),
+        ("connectivity.sensors", "Features", "Sensors", !connectivity.sensors.is_empty()),
+        ("tests", "Tests", "", !details.tests.benchmarks.is_empty() || details.tests.eu_label.is_some()),
+        ("model_codes", "Misc", "Models", !details.model_codes.is_empty()),
+        ("prices", "Misc", "Price", !details.prices.is_empty()),
+    ];
+    
+    for (field, section, label, populated) in table_fields {
+        let row = if label.is_empty() {
+            sheet.section(section).next()
+        } else {
+            sheet.get(section, label)
+        };
+        
+        if populated && row.is_some() {
+            let source = FieldSource::TableRow { section: section.to_string(), label: label.to_string() };
+            details.record_provenance(field, source, Confidence::High);
+        }
+    }
+    
+    // Sem linha "Infrared port" numa seção Comms presente, a ausência vale como "não tem"
+    if details.connectivity.infrared.is_some() && sheet.get("Comms", "Infrared port").is_none() {
+        let source = FieldSource::TableRow { section: "Comms".to_string(), label: String::new() };
+        details.record_provenance("connectivity.infrared", source, Confidence::Medium);
+    }
+    
+    // Gerações de rede: bandas listadas ou tecnologias da linha Technology
+    for generation in ["2g", "3g", "4g", "5g"] {
+        let bands = format!("connectivity.bands_{}", generation);
+        let inputs: Vec<&str> = [bands.as_str(), "connectivity.network_technologies"].into_iter()
+            .filter(|field| details.provenance.contains_key(*field))
+            .collect();
+        
+        if !inputs.is_empty() {
+            let confidence = details.derived_confidence(&inputs);
+            let source = FieldSource::Derived { from: inputs.iter().map(|field| field.to_string()).collect() };
+            details.record_provenance(&format!("connectivity.has_{}", generation), source, confidence);
+        }
+    }
+    
+    // Módulos de câmera: a linha com rótulo de quantidade ("Single", "Triple"...)
+    for (field, section, position) in [
+        ("camera.rear", "Main Camera", CameraPosition::Rear),
+        ("camera.front", "Selfie camera", CameraPosition::Front),
+    ] {
+        let row = sheet.section(section).find(|row| {
+            let label = row.label.to_lowercase();
+            !label.is_empty() && label != "video" && label != "features"
+        });
+        
+        if let Some(row) = row
+            && details.camera.modules_at(position).next().is_some() {
+            let source = FieldSource::TableRow { section: section.to_string(), label: row.label.clone() };
+            details.record_provenance(field, source, Confidence::High);
+        }
+    }
+    
+    // "Versions" aparece fora da Misc em algumas páginas
+    if !details.versions.is_empty()
+        && let Some(row) = sheet.rows.iter().find(|r| r.label.eq_ignore_ascii_case("Versions")) {
+        let source = FieldSource::TableRow { section: row.section.clone(), label: row.label.clone() };
+        details.record_provenance("versions", source, Confidence::High);
+    }
+    
+    // Cada tela vem da sua linha: principal em Size/Resolution, externas nas linhas sem rótulo
+    let panels: Vec<(String, &str)> = details.displays.iter()
+        .map(|panel| {
+            let label = match panel.role {
+                DisplayRole::Main if sheet.get("Display", "Size").is_some() => "Size",
+                DisplayRole::Main => "Resolution",
+                _ => "",
+            };
+            (format!("displays.{:?}", panel.role).to_lowercase(), label)
+        })
+        .collect();
+    
+    for (field, label) in panels {
+        let source = FieldSource::TableRow { section: "Display".to_string(), label: label.to_string() };
+        details.record_provenance(&field, source, Confidence::High);
+    }
+    
+    if details.engagement.hits.is_some() || details.engagement.fans.is_some() {
+        details.record_provenance("engagement", FieldSource::PageHeader, Confidence::Medium);
+    }
+    
+    // DPR/viewport saem de heurística por OS
+    if details.displays.iter().any(|d| d.geometry.is_some()) {
+        let source = FieldSource::Derived { from: vec!["displays".to_string(), "platform.os_family".to_string()] };
+        details.record_provenance("displays.geometry", source, Confidence::Medium);
+    }
+}
+
+// Separa por `separator` ignorando o que está entre parênteses
+// (ex: "Glass front (Gorilla Glass 5, 2.5D), plastic back" -> 2 partes)
+pub(crate) fn split_top_level(text: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        
+        if c == separator && depth == 0 {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    parts.push(current);
+    
+    parts.into_iter()
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+fn clean_text(text: &str) -> String {
+    text.replace('\u{a0}', " ").trim().to_string()
+}
+
+// Texto da célula separado nas quebras <br>
+fn cell_lines(cell: &Node) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    
+    for child in cell.children() {
+        if child.name() == Some("br") {
+            lines.push(std::mem::take(&mut current));
+        } else {
+            current.push_str(&child.text());
+        }
+    }
+    lines.push(current);
+    
+    lines.iter()
+        .flat_map(|line| line.lines().map(clean_text).collect::<Vec<_>>())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    // Página mínima no formato da tabela de specs, uma linha por (seção, rótulo, valor)
+    fn spec_page(rows: &[(&str, &str, &str)]) -> String {
+        let rows: String = rows.iter()
+            .map(|(section, label, value)| format!(
+                "<tr><th>{}</th><td class=\"ttl\">{}</td><td class=\"nfo\">{}</td></tr>",
+                section, label, value.replace('\n', "<br>"),
+            ))
+            .collect();
+        
+        format!("<html><body><div id=\"specs-list\"><table>{}</table></div></body></html>", rows)
+    }
+    
+    fn table_row(details: &PhoneDetails, field: &str) -> Option<(String, String)> {
+        match &details.provenance.get(field)?.source {
+            FieldSource::TableRow { section, label } => Some((section.clone(), label.clone())),
+            _ => None,
+        }
+    }
+    
+    #[test]
+    fn each_display_panel_credits_its_own_row() {
+        let html = spec_page(&[
+            ("Display", "Type", "Dynamic AMOLED 2X, 120Hz, HDR10+"),
+            ("Display", "Size", "7.6 inches, 185.2 cm2 (~89.0% screen-to-body ratio)"),
+            ("Display", "Resolution", "1856 x 2160 pixels (~374 ppi density)"),
+            ("Display", "", "Cover display:\nDynamic AMOLED 2X, 120Hz, 6.3 inches, 968 x 2376 pixels, 21:9 ratio, 410 ppi"),
+        ]);
+        let mut details = empty_details();
+        extract_all(&html, &mut details);
+        
+        let s = |text: &str| text.to_string();
+        assert_eq!(details.displays.len(), 2);
+        assert_eq!(table_row(&details, "displays.main"), Some((s("Display"), s("Size"))));
+        assert_eq!(table_row(&details, "displays.cover"), Some((s("Display"), s(""))));
+        assert!(!details.provenance.contains_key("displays"));
+    }
+    
+    #[test]
+    fn main_panel_without_size_row_credits_resolution() {
+        let html = spec_page(&[("Display", "Resolution", "1080 x 2340 pixels, 19.5:9 ratio (~411 ppi density)")]);
+        let mut details = empty_details();
+        extract_all(&html, &mut details);
+        
+        assert_eq!(table_row(&details, "displays.main"), Some(("Display".to_string(), "Resolution".to_string())));
+    }
+    
+    #[test]
+    fn sub_fields_credit_their_rows() {
+        let html = spec_page(&[
+            ("Network", "Technology", "GSM / HSPA / LTE / 5G"),
+            ("Network", "5G bands", "1, 3, 5, 7, 8, 20, 28, 38, 40, 41, 77, 78 SA/NSA"),
+            ("Display", "Type", "Dynamic LTPO AMOLED 2X, 120Hz, HDR10+, 1000 nits (typ), 2600 nits (peak)"),
+            ("Display", "Size", "6.2 inches, 94.4 cm2 (~90.9% screen-to-body ratio)"),
+            ("Memory", "Card slot", "No"),
+            ("Memory", "Internal", "128GB 8GB RAM, 256GB 8GB RAM"),
+            ("Memory", "", "UFS 3.1"),
+            ("Main Camera", "Triple", "50 MP, f/1.8, 24mm (wide), 1/1.56\", 1.0µm, dual pixel PDAF, OIS"),
+            ("Main Camera", "Video", "8K@24/30fps, 4K@30/60fps, HDR10+"),
+            ("Selfie camera", "Single", "12 MP, f/2.2, 26mm (wide), dual pixel PDAF"),
+            ("Comms", "WLAN", "Wi-Fi 802.11 a/b/g/n/ac/6e, tri-band"),
+            ("Comms", "Bluetooth", "5.3, A2DP, LE"),
+            ("Comms", "NFC", "Yes"),
+            ("Comms", "USB", "USB Type-C 3.2, OTG"),
+            ("Sound", "3.5mm jack", "No"),
+            ("Features", "Sensors", "Fingerprint (under display, ultrasonic), accelerometer"),
+            ("Tests", "Versions", "SM-S921B (Global); SM-S921U (USA)"),
+        ]);
+        let mut details = empty_details();
+        extract_all(&html, &mut details);
+        
+        let row = |section: &str, label: &str| Some((section.to_string(), label.to_string()));
+        for (field, expected) in [
+            ("hdr_formats", row("Display", "Type")),
+            ("brightness_typical_nits", row("Display", "Type")),
+            ("brightness_peak_nits", row("Display", "Type")),
+            ("memory.variants", row("Memory", "Internal")),
+            ("memory.storage_types", row("Memory", "")),
+            ("memory.card_slot_supported", row("Memory", "Card slot")),
+            ("camera.rear", row("Main Camera", "Triple")),
+            ("camera.main_video", row("Main Camera", "Video")),
+            ("camera.front", row("Selfie camera", "Single")),
+            ("connectivity.network_technologies", row("Network", "Technology")),
+            ("connectivity.bands_5g", row("Network", "5G bands")),
+            ("connectivity.wifi_standard", row("Comms", "WLAN")),
+            ("connectivity.bluetooth_version", row("Comms", "Bluetooth")),
+            ("connectivity.usb_version", row("Comms", "USB")),
+            ("connectivity.headphone_jack", row("Sound", "3.5mm jack")),
+            ("connectivity.sensors", row("Features", "Sensors")),
+            ("versions", row("Tests", "Versions")),
+        ] {
+            assert_eq!(table_row(&details, field), expected, "{}", field);
+        }
+        
+        // Campos sem valor na página não ganham origem
+        assert!(!details.provenance.contains_key("camera.selfie_video"));
+        assert!(!details.provenance.contains_key("connectivity.bands_2g"));
+    }
+    
+    #[test]
+    fn inferred_and_derived_connectivity() {
+        let html = spec_page(&[
+            ("Network", "Technology", "GSM / HSPA / LTE"),
+            ("Comms", "NFC", "Yes"),
+        ]);
+        let mut details = empty_details();
+        extract_all(&html, &mut details);
+        
+        // Infravermelho inferido pela ausência da linha na seção Comms
+        let infrared = &details.provenance["connectivity.infrared"];
+        assert_eq!(details.connectivity.infrared, Some(false));
+        assert_eq!(infrared.confidence, Confidence::Medium);
+        assert_eq!(table_row(&details, "connectivity.infrared"), Some(("Comms".to_string(), String::new())));
+        
+        // has_4g vem da linha Technology, sem bandas 4G listadas
+        assert_eq!(details.provenance["connectivity.has_4g"].source, FieldSource::Derived {
+            from: vec!["connectivity.network_technologies".to_string()],
+        });
+    }
+}
@@ -0,0 +1,133 @@
+use regex::Regex;
+
+use crate::models::{BatteryChemistry, BatterySpecs, ChargeClaim, PhoneDetails};
+use crate::specs::SpecSheet;
+
+// Seção "Battery": Type (capacidade, química, removível) e Charging
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let mut battery = BatterySpecs::default();
+    
+    // Páginas antigas não têm rótulo "Type" (a linha vem sem rótulo)
+    let type_text = sheet.value("Battery", "Type")
+        .or_else(|| sheet.section("Battery").find(|row| row.label.is_empty()).map(|row| row.value()));
+    
+    if let Some(text) = &type_text {
+        parse_type(text, &mut battery);
+        details.battery = Some(text.replace('\n', ", "));
+    }
+    
+    if let Some(charging) = sheet.get("Battery", "Charging") {
+        for line in &charging.lines {
+            parse_charging_line(line, &mut battery);
+        }
+    }
+    
+    details.battery_specs = battery;
+}
+
+// "Li-Ion 4000 mAh, non-removable" / "Si/C Li-Ion 6000 mAh" / "Removable Li-Ion 1500 mAh battery"
+pub fn parse_type(text: &str, battery: &mut BatterySpecs) {
+    let lower = text.to_lowercase();
+    
+    battery.capacity_mah = Regex::new(r"(?i)(\d+)\s*mAh")
+        .ok()
+        .and_then(|re| re.captures(text))
+        .and_then(|cap| cap[1].parse().ok());
+    
+    battery.chemistry = if lower.contains("si/c") || lower.contains("silicon") {
+        Some(BatteryChemistry::SiliconCarbon)
+    } else if lower.contains("li-po") || lower.contains("li-polymer") {
+        Some(BatteryChemistry::LiPo)
+    } else if lower.contains("li-ion") {
+        Some(BatteryChemistry::LiIon)
+    } else {
+        None
+    };
+    
+    battery.removable = if lower.contains("non-removable") || lower.contains("non removable") {
+        Some(false)
+    } else if lower.contains("removable") {
+        Some(true)
+    } else {
+        None
+    };
+}
+
+// "25W wired, PD3.0, 50% in 30 min (advertised)" / "15W wireless (Qi/PMA)" / "4.5W reverse wireless"
+pub fn parse_charging_line(line: &str, battery: &mut BatterySpecs) {
+    let lower = line.to_lowercase();
+    let watts = Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*W\b")
+        .ok()
+        .and_then(|re| re.captures(line))
+        .and_then(|cap| cap[1].parse::<f64>().ok());
+    
+    if lower.contains("reverse") {
+        battery.reverse_charging = true;
+        if lower.contains("wireless") {
+            battery.reverse_wireless_w = battery.reverse_wireless_w.or(watts);
+        } else {
+            battery.reverse_wired_w = battery.reverse_wired_w.or(watts);
+        }
+    } else if lower.contains("wireless") || lower.contains("magsafe") || lower.contains("qi2") {
+        battery.wireless_w = max_watts(battery.wireless_w, watts);
+    } else if lower.contains("wired") || lower.contains("fast") || lower.contains("charging") {
+        battery.wired_w = max_watts(battery.wired_w, watts);
+    }
+    
+    if let Ok(re) = Regex::new(r"(\d+)%\s*in\s*(\d+)\s*min") {
+        for cap in re.captures_iter(line) {
+            if let (Ok(percent), Ok(minutes)) = (cap[1].parse(), cap[2].parse()) {
+                battery.charge_claims.push(ChargeClaim { percent, minutes });
+            }
+        }
+    }
+}
+
+fn max_watts(current: Option<f64>, new: Option<f64>) -> Option<f64> {
+    match (current, new) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    #[test]
+    fn capacity_chemistry_and_removable() {
+        let mut battery = BatterySpecs::default();
+        parse_type("Li-Ion 4000 mAh, non-removable", &mut battery);
+        assert_eq!(battery.capacity_mah, Some(4000));
+        assert_eq!(battery.chemistry, Some(BatteryChemistry::LiIon));
+        assert_eq!(battery.removable, Some(false));
+        
+        let mut battery = BatterySpecs::default();
+        parse_type("Si/C Li-Ion 6000 mAh", &mut battery);
+        assert_eq!(battery.chemistry, Some(BatteryChemistry::SiliconCarbon));
+        
+        let mut battery = BatterySpecs::default();
+        parse_type("Removable Li-Po 1500 mAh battery", &mut battery);
+        assert_eq!(battery.chemistry, Some(BatteryChemistry::LiPo));
+        assert_eq!(battery.removable, Some(true));
+    }
+    
+    #[test]
+    fn charging_rows() {
+        let sheet = SpecSheet::from_rows(&[
+            ("Battery", "Type", "Li-Ion 4000 mAh, non-removable"),
+            ("Battery", "Charging", "25W wired, PD3.0, 50% in 30 min (advertised)\n15W wireless (Qi/PMA)\n4.5W reverse wireless"),
+        ]);
+        let mut details = empty_details();
+        extract(&sheet, &mut details);
+        
+        let battery = &details.battery_specs;
+        assert_eq!(battery.wired_w, Some(25.0));
+        assert_eq!(battery.wireless_w, Some(15.0));
+        assert!(battery.reverse_charging);
+        assert_eq!(battery.reverse_wireless_w, Some(4.5));
+        assert_eq!(battery.charge_claims, [ChargeClaim { percent: 50, minutes: 30 }]);
+        assert_eq!(details.battery.as_deref(), Some("Li-Ion 4000 mAh, non-removable"));
+    }
+}
@@ -0,0 +1,173 @@
+use regex::Regex;
+
+use crate::models::{BodyDimensions, BodySpecs, PhoneDetails};
+use crate::specs::{split_top_level, SpecSheet};
+
+// Seção "Body": dimensões, peso, materiais, SIM e certificações
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let mut body = BodySpecs::default();
+    
+    if let Some(text) = sheet.value("Body", "Dimensions") {
+        body.dimensions = parse_dimensions(&text);
+    }
+    
+    body.weight_g = sheet.value("Body", "Weight")
+        .and_then(|text| parse_weight(&text));
+    
+    if let Some(text) = sheet.value("Body", "Build") {
+        let (front, back, frame) = parse_build(&text);
+        body.front_material = front;
+        body.back_material = back;
+        body.frame_material = frame;
+    }
+    
+    if let Some(text) = sheet.value("Body", "SIM") {
+        let lower = text.to_lowercase();
+        body.esim = lower.contains("esim");
+        body.dual_sim = lower.contains("dual sim") || lower.contains("dual-sim");
+        body.sim = Some(text.replace('\n', ", "));
+    }
+    
+    // IP e MIL-STD aparecem nas linhas sem rótulo (às vezes também no Build)
+    let all_text = sheet.section("Body")
+        .map(|row| row.value())
+        .collect::<Vec<_>>()
+        .join("\n");
+    body.ip_ratings = parse_ip_ratings(&all_text);
+    body.mil_std = parse_mil_std(&all_text);
+    
+    details.body = body;
+}
+
+// "147 x 70.6 x 7.6 mm" ou "Unfolded: 153.5 x 132.6 x 5.6 mm\nFolded: 153.5 x 67.1 x 13.4 mm"
+pub fn parse_dimensions(text: &str) -> Vec<BodyDimensions> {
+    let Ok(re) = Regex::new(r"(?i)(?:\b(unfolded|folded|closed|open)\s*:?\s*)?(\d+(?:\.\d+)?)\s*x\s*(\d+(?:\.\d+)?)\s*x\s*(\d+(?:\.\d+)?)\s*mm") else {
+        return Vec::new();
+    };
+    
+    re.captures_iter(text)
+        .filter_map(|cap| {
+            Some(BodyDimensions {
+                state: cap.get(1).map(|m| m.as_str().to_lowercase()),
+                height_mm: cap[2].parse().ok()?,
+                width_mm: cap[3].parse().ok()?,
+                thickness_mm: cap[4].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+// "167 g or 168 g (5.89 oz)" -> 167 (primeira versão citada)
+pub fn parse_weight(text: &str) -> Option<f64> {
+    Regex::new(r"(\d+(?:\.\d+)?)\s*g\b")
+        .ok()?
+        .captures(text)
+        .and_then(|cap| cap[1].parse().ok())
+}
+
+// "Glass front (Gorilla Glass Victus 2), glass back (...), aluminum frame" -> (frente, traseira, moldura)
+pub fn parse_build(text: &str) -> (Option<String>, Option<String>, Option<String>) {
+    let mut front = None;
+    let mut back = None;
+    let mut frame = None;
+    
+    for part in split_top_level(text, ',') {
+        let lower = part.to_lowercase();
+        let (slot, word) = if lower.contains(" front") {
+            (&mut front, "front")
+        } else if lower.contains(" back") {
+            (&mut back, "back")
+        } else if lower.contains(" frame") {
+            (&mut frame, "frame")
+        } else {
+            continue;
+        };
+        
+        // Remover a palavra da posição mantendo a proteção entre parênteses
+        let material = part.replacen(word, "", 1)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut chars = material.chars();
+        let material: String = match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        };
+        
+        if slot.is_none() && !material.is_empty() {
+            *slot = Some(material);
+        }
+    }
+    
+    (front, back, frame)
+}
+
+pub fn parse_ip_ratings(text: &str) -> Vec<String> {
+    let Ok(re) = Regex::new(r"\b(IP[0-9X]{2}K?)\b") else {
+        return Vec::new();
+    };
+    
+    let mut ratings: Vec<String> = Vec::new();
+    for cap in re.captures_iter(text) {
+        if !ratings.contains(&cap[1].to_string()) {
+            ratings.push(cap[1].to_string());
+        }
+    }
+    
+    ratings
+}
+
+pub fn parse_mil_std(text: &str) -> Option<String> {
+    Regex::new(r"(?i)\b(MIL-STD-\d+[A-Z]?)")
+        .ok()?
+        .captures(text)
+        .map(|cap| cap[1].to_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    #[test]
+    fn single_and_folded_dimensions() {
+        let bar = parse_dimensions("147 x 70.6 x 7.6 mm (5.79 x 2.78 x 0.30 in)");
+        assert_eq!(bar.len(), 1);
+        assert_eq!((bar[0].height_mm, bar[0].width_mm, bar[0].thickness_mm), (147.0, 70.6, 7.6));
+        assert_eq!(bar[0].state, None);
+        
+        let fold = parse_dimensions("Unfolded: 153.5 x 132.6 x 5.6 mm\nFolded: 153.5 x 67.1 x 13.4 mm");
+        let states: Vec<_> = fold.iter().map(|d| d.state.as_deref()).collect();
+        assert_eq!(states, [Some("unfolded"), Some("folded")]);
+        assert_eq!(fold[1].thickness_mm, 13.4);
+    }
+    
+    #[test]
+    fn weight_takes_first_version() {
+        assert_eq!(parse_weight("167 g or 168 g (5.89 oz)"), Some(167.0));
+    }
+    
+    #[test]
+    fn build_materials() {
+        let (front, back, frame) = parse_build("Glass front (Gorilla Glass Victus 2), glass back (Gorilla Glass Victus 2), aluminum frame");
+        
+        assert_eq!(front.as_deref(), Some("Glass (Gorilla Glass Victus 2)"));
+        assert_eq!(back.as_deref(), Some("Glass (Gorilla Glass Victus 2)"));
+        assert_eq!(frame.as_deref(), Some("Aluminum"));
+    }
+    
+    #[test]
+    fn sim_and_ratings() {
+        let sheet = SpecSheet::from_rows(&[
+            ("Body", "SIM", "Nano-SIM and eSIM or Dual SIM (2 Nano-SIMs, dual stand-by)"),
+            ("Body", "", "IP68 dust/water resistant (up to 6m for 30 min)\nMIL-STD-810H compliant"),
+        ]);
+        let mut details = empty_details();
+        extract(&sheet, &mut details);
+        
+        assert!(details.body.esim);
+        assert!(details.body.dual_sim);
+        assert_eq!(details.body.ip_ratings, ["IP68"]);
+        assert_eq!(details.body.mil_std.as_deref(), Some("MIL-STD-810H"));
+    }
+}
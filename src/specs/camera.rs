@@ -0,0 +1,203 @@
+use regex::Regex;
+
+use crate::models::{CameraModule, CameraPosition, CameraRole, CameraSpecs, PhoneDetails, VideoCapabilities, VideoMode};
+use crate::specs::{split_top_level, SpecSheet};
+
+// Seções "Main Camera" e "Selfie camera": módulos (linha Single/Dual/Triple...), Features e Video
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let mut camera = CameraSpecs::default();
+    
+    for (section, position) in [("Main Camera", CameraPosition::Rear), ("Selfie camera", CameraPosition::Front)] {
+        for row in sheet.section(section) {
+            let label = row.label.to_lowercase();
+            
+            if label == "video" {
+                let video = parse_video(&row.lines.join(", "));
+                match position {
+                    CameraPosition::Rear => camera.main_video = video,
+                    CameraPosition::Front => camera.selfie_video = video,
+                }
+            } else if label == "features" {
+                let features = row.lines.iter()
+                    .flat_map(|line| split_top_level(line, ','))
+                    .collect::<Vec<_>>();
+                match position {
+                    CameraPosition::Rear => camera.main_features = features,
+                    CameraPosition::Front => camera.selfie_features = features,
+                }
+            } else if !label.is_empty() {
+                // Uma linha por módulo: "50 MP, f/1.8, 24mm (wide), 1/1.56\", 1.0µm, dual pixel PDAF, OIS"
+                camera.modules.extend(row.lines.iter()
+                    .filter_map(|line| parse_module(line, position)));
+            }
+        }
+    }
+    
+    details.camera = camera;
+}
+
+pub fn parse_module(line: &str, position: CameraPosition) -> Option<CameraModule> {
+    let lower = line.to_lowercase();
+    
+    let megapixels = capture_f64(r"(?i)(\d+(?:\.\d+)?)\s*MP\b", line);
+    let role = parse_role(&lower);
+    
+    // Linhas sem MP nem função reconhecida são observações ("Super Steady video" etc.)
+    if megapixels.is_none() && role.is_none() {
+        return None;
+    }
+    
+    Some(CameraModule {
+        position,
+        megapixels,
+        aperture: capture_f64(r"(?i)f/(\d+(?:\.\d+)?)", line),
+        focal_length_mm: capture_f64(r"(\d+(?:\.\d+)?)\s*mm\b", line),
+        sensor_size: Regex::new(r#"(1/\d+(?:\.\d+)?|\d(?:\.\d+)?)\s*(?:"|''|-inch)"#)
+            .ok()
+            .and_then(|re| re.captures(line))
+            .map(|cap| format!("{}\"", &cap[1])),
+        role,
+        ois: Regex::new(r"\bOIS\b").is_ok_and(|re| re.is_match(line)),
+        pdaf: lower.contains("pdaf"),
+        optical_zoom: capture_f64(r"(?i)(\d+(?:\.\d+)?)\s*x\s+optical\s+zoom", line),
+    })
+}
+
+fn parse_role(lower: &str) -> Option<CameraRole> {
+    let role = if lower.contains("periscope") {
+        CameraRole::Periscope
+    } else if lower.contains("telephoto") {
+        CameraRole::Telephoto
+    } else if lower.contains("ultrawide") || lower.contains("ultra wide") {
+        CameraRole::Ultrawide
+    } else if lower.contains("macro") {
+        CameraRole::Macro
+    } else if lower.contains("depth") || Regex::new(r"\btof\b").is_ok_and(|re| re.is_match(lower)) {
+        CameraRole::Depth
+    } else if lower.contains("b/w") || lower.contains("monochrome") {
+        CameraRole::Monochrome
+    } else if lower.contains("wide") {
+        CameraRole::Wide
+    } else {
+        return None;
+    };
+    
+    Some(role)
+}
+
+// "8K@24/30fps, 4K@30/60fps, 1080p@30/60/240fps, 720p@960fps, HDR10+"
+pub fn parse_video(text: &str) -> Option<VideoCapabilities> {
+    let re = Regex::new(r"(?i)(\d+)\s*([kp])\s*@\s*([\d/]+)\s*fps").ok()?;
+    
+    let mut modes: Vec<VideoMode> = re.captures_iter(text)
+        .map(|cap| VideoMode {
+            resolution: format!("{}{}", &cap[1], cap[2].to_lowercase()).replace('k', "K"),
+            fps: cap[3].split('/').filter_map(|f| f.parse().ok()).collect(),
+        })
+        .filter(|mode| !mode.fps.is_empty())
+        .collect();
+    
+    // Páginas antigas: só "1080p" ou "720p", sem fps
+    if modes.is_empty() {
+        let plain = Regex::new(r"(?i)\b(\d{3,4})p\b").ok()?;
+        modes = plain.captures_iter(text)
+            .map(|cap| VideoMode { resolution: format!("{}p", &cap[1]), fps: Vec::new() })
+            .collect();
+    }
+    
+    let best = modes.iter().max_by_key(|mode| video_lines(&mode.resolution))?;
+    
+    Some(VideoCapabilities {
+        max_resolution: best.resolution.clone(),
+        max_resolution_fps: best.fps.iter().max().copied(),
+        max_fps: modes.iter().flat_map(|mode| mode.fps.iter()).max().copied(),
+        modes: modes.clone(),
+    })
+}
+
+// Linhas verticais aproximadas para ordenar resoluções (8K > 4K > 1080p)
+fn video_lines(resolution: &str) -> u32 {
+    let number: u32 = resolution.trim_end_matches(['K', 'p']).parse().unwrap_or(0);
+    
+    if resolution.ends_with('K') {
+        number * 540
+    } else {
+        number
+    }
+}
+
+fn capture_f64(pattern: &str, text: &str) -> Option<f64> {
+    Regex::new(pattern)
+        .ok()?
+        .captures(text)
+        .and_then(|cap| cap[1].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    #[test]
+    fn wide_module_with_ois_and_pdaf() {
+        let module = parse_module("50 MP, f/1.8, 24mm (wide), 1/1.56\", 1.0µm, dual pixel PDAF, OIS", CameraPosition::Rear).unwrap();
+        
+        assert_eq!(module.megapixels, Some(50.0));
+        assert_eq!(module.aperture, Some(1.8));
+        assert_eq!(module.focal_length_mm, Some(24.0));
+        assert_eq!(module.sensor_size.as_deref(), Some("1/1.56\""));
+        assert_eq!(module.role, Some(CameraRole::Wide));
+        assert!(module.ois && module.pdaf);
+    }
+    
+    #[test]
+    fn telephoto_and_ultrawide_roles() {
+        let tele = parse_module("10 MP, f/2.4, 67mm (telephoto), 1/3.94\", 1.0µm, PDAF, OIS, 3x optical zoom", CameraPosition::Rear).unwrap();
+        assert_eq!(tele.role, Some(CameraRole::Telephoto));
+        assert_eq!(tele.optical_zoom, Some(3.0));
+        
+        let ultrawide = parse_module("12 MP, f/2.2, 13mm, 120˚ (ultrawide), 1/2.55\" 1.4µm", CameraPosition::Rear).unwrap();
+        assert_eq!(ultrawide.role, Some(CameraRole::Ultrawide));
+        assert!(!ultrawide.ois);
+        
+        assert!(parse_module("Super Steady video", CameraPosition::Rear).is_none());
+    }
+    
+    #[test]
+    fn autofocus_is_not_a_tof_sensor() {
+        let wide = parse_module("13 MP, f/2.0, 26mm (wide), autofocus", CameraPosition::Rear).unwrap();
+        assert_eq!(wide.role, Some(CameraRole::Wide));
+        
+        let tof = parse_module("0.3 MP, TOF 3D", CameraPosition::Rear).unwrap();
+        assert_eq!(tof.role, Some(CameraRole::Depth));
+    }
+    
+    #[test]
+    fn video_capabilities() {
+        let video = parse_video("8K@24/30fps, 4K@30/60fps, 1080p@30/60/240fps, 720p@960fps, HDR10+").unwrap();
+        
+        assert_eq!(video.max_resolution, "8K");
+        assert_eq!(video.max_resolution_fps, Some(30));
+        assert_eq!(video.max_fps, Some(960));
+        assert_eq!(video.modes.len(), 4);
+        
+        assert_eq!(parse_video("1080p").unwrap().max_resolution_fps, None);
+    }
+    
+    #[test]
+    fn rear_and_selfie_sections() {
+        let sheet = SpecSheet::from_rows(&[
+            ("Main Camera", "Dual", "50 MP, f/1.8, 24mm (wide), PDAF, OIS\n12 MP, f/2.2, 13mm (ultrawide)"),
+            ("Main Camera", "Video", "4K@30/60fps, 1080p@30fps"),
+            ("Selfie camera", "Single", "12 MP, f/2.2, 26mm (wide)"),
+            ("Selfie camera", "Video", "4K@30fps"),
+        ]);
+        let mut details = empty_details();
+        extract(&sheet, &mut details);
+        
+        assert_eq!(details.camera.modules_at(CameraPosition::Rear).count(), 2);
+        assert_eq!(details.camera.modules_at(CameraPosition::Front).count(), 1);
+        assert_eq!(details.camera.main_video.map(|v| v.max_resolution).as_deref(), Some("4K"));
+        assert_eq!(details.camera.selfie_video.and_then(|v| v.max_fps), Some(30));
+    }
+}
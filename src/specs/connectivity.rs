@@ -0,0 +1,260 @@
+use regex::Regex;
+
+use crate::models::{ConnectivitySpecs, PhoneDetails};
+use crate::specs::{split_top_level, SpecSheet};
+
+// Seções "Network", "Comms", "Sound" (fone) e "Features" (sensores)
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let mut connectivity = ConnectivitySpecs::default();
+    
+    if let Some(text) = sheet.value("Network", "Technology") {
+        connectivity.network_technologies = text.split('/')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+    }
+    
+    for (label, bands) in [
+        ("2G bands", &mut connectivity.bands_2g),
+        ("3G bands", &mut connectivity.bands_3g),
+        ("4G bands", &mut connectivity.bands_4g),
+        ("5G bands", &mut connectivity.bands_5g),
+    ] {
+        *bands = parse_band_rows(sheet, label);
+    }
+    
+    let technologies = connectivity.network_technologies.join(" ").to_uppercase();
+    let has_technology = |names: &[&str]| names.iter().any(|name| technologies.split_whitespace().any(|t| t == *name));
+    connectivity.has_2g = !connectivity.bands_2g.is_empty() || has_technology(&["GSM", "CDMA"]);
+    connectivity.has_3g = !connectivity.bands_3g.is_empty() || has_technology(&["HSPA", "UMTS", "EVDO", "CDMA2000", "TD-SCDMA"]);
+    connectivity.has_4g = !connectivity.bands_4g.is_empty() || has_technology(&["LTE"]);
+    connectivity.has_5g = !connectivity.bands_5g.is_empty() || has_technology(&["5G"]);
+    
+    if let Some(text) = sheet.value("Comms", "WLAN") {
+        connectivity.wifi_standard = parse_wifi_standard(&text);
+        connectivity.wlan = Some(text);
+    }
+    
+    connectivity.bluetooth_version = sheet.value("Comms", "Bluetooth")
+        .and_then(|text| {
+            Regex::new(r"^\s*v?(\d+(?:\.\d+)?)")
+                .ok()?
+                .captures(&text)
+                .map(|cap| cap[1].to_string())
+        });
+    
+    if let Some(text) = sheet.value("Comms", "Positioning") {
+        connectivity.positioning = split_top_level(&text, ',')
+            .iter()
+            .map(|system| system.split('(').next().unwrap_or(system).trim().to_string())
+            .filter(|system| !system.is_empty())
+            .collect();
+    }
+    
+    if let Some(text) = sheet.value("Comms", "USB") {
+        let (connector, version) = parse_usb(&text);
+        connectivity.usb_connector = connector;
+        connectivity.usb_version = version;
+    }
+    
+    let has_comms = sheet.section("Comms").next().is_some();
+    connectivity.nfc = sheet.value("Comms", "NFC").and_then(|text| parse_yes_no(&text));
+    connectivity.fm_radio = sheet.value("Comms", "Radio").and_then(|text| {
+        let lower = text.to_lowercase();
+        if lower.contains("fm") { Some(true) } else { parse_yes_no(&text) }
+    });
+    
+    // O GSMArena só lista "Infrared port" quando existe
+    connectivity.infrared = match sheet.value("Comms", "Infrared port") {
+        Some(text) => parse_yes_no(&text),
+        None if has_comms => Some(false),
+        None => None,
+    };
+    
+    connectivity.headphone_jack = sheet.value("Sound", "3.5mm jack")
+        .and_then(|text| parse_yes_no(&text));
+    
+    if let Some(text) = sheet.value("Features", "Sensors") {
+        connectivity.sensors = text.lines()
+            .flat_map(|line| split_top_level(line, ','))
+            .collect();
+    }
+    
+    details.connectivity = connectivity;
+}
+
+// Junta as bandas de todas as linhas regionais da seção Network
+// ("GSM 850 / 900 - SIM 1 & SIM 2" -> ["GSM 850", "GSM 900"], "1, 3, 28 SA/NSA - International" -> ["1", "3", "28"])
+pub fn parse_band_rows(sheet: &SpecSheet, label: &str) -> Vec<String> {
+    let mut bands: Vec<String> = Vec::new();
+    let mut in_label = false;
+    
+    // As linhas seguintes sem rótulo continuam a mesma geração
+    for row in sheet.section("Network") {
+        if row.label.eq_ignore_ascii_case(label) {
+            in_label = true;
+        } else if !row.label.is_empty() {
+            in_label = false;
+        }
+        
+        if !in_label {
+            continue;
+        }
+        
+        for line in &row.lines {
+            for band in parse_band_line(line) {
+                if !bands.contains(&band) {
+                    bands.push(band);
+                }
+            }
+        }
+    }
+    
+    bands
+}
+
+pub fn parse_band_line(line: &str) -> Vec<String> {
+    let text = line.split(" - ").next().unwrap_or(line);
+    let Ok(re) = Regex::new(r"(?:([A-Za-z][A-Za-z0-9-]*)\s+)?\b(\d+(?:\([A-Za-z]+\)|\b))") else {
+        return Vec::new();
+    };
+    
+    let mut technology: Option<String> = None;
+    let mut bands = Vec::new();
+    
+    for cap in re.captures_iter(text) {
+        if let Some(tech) = cap.get(1) {
+            technology = Some(tech.as_str().to_string());
+        }
+        
+        match &technology {
+            Some(tech) => bands.push(format!("{} {}", tech, &cap[2])),
+            None => bands.push(cap[2].to_string()),
+        }
+    }
+    
+    bands
+}
+
+// "Wi-Fi 802.11 a/b/g/n/ac/6e, tri-band" -> "Wi-Fi 6E"
+pub fn parse_wifi_standard(text: &str) -> Option<String> {
+    let lower = text.to_lowercase();
+    let tokens: Vec<&str> = lower
+        .split(|c: char| c == '/' || c == ',' || c.is_whitespace())
+        .collect();
+    let has = |token: &str| tokens.contains(&token);
+    
+    let standard = if has("be") || has("7") {
+        "Wi-Fi 7"
+    } else if has("6e") {
+        "Wi-Fi 6E"
+    } else if has("ax") || has("6") {
+        "Wi-Fi 6"
+    } else if has("ac") {
+        "Wi-Fi 5"
+    } else if has("n") {
+        "Wi-Fi 4"
+    } else if has("g") || has("b") || has("a") {
+        "Wi-Fi (802.11a/b/g)"
+    } else {
+        return None;
+    };
+    
+    Some(standard.to_string())
+}
+
+// "USB Type-C 3.2, DisplayPort 1.2, OTG" -> ("USB Type-C", "3.2")
+pub fn parse_usb(text: &str) -> (Option<String>, Option<String>) {
+    let lower = text.to_lowercase();
+    
+    let connector = if lower.contains("type-c") {
+        Some("USB Type-C")
+    } else if lower.contains("lightning") {
+        Some("Lightning")
+    } else if lower.contains("microusb") || lower.contains("micro usb") {
+        Some("microUSB")
+    } else if lower.contains("miniusb") || lower.contains("mini usb") {
+        Some("miniUSB")
+    } else {
+        None
+    };
+    
+    // Versão no trecho que fala de USB ("Lightning, USB 2.0"), não no "DisplayPort 1.2"
+    let usb_part = text.split(',')
+        .find(|part| part.to_lowercase().contains("usb"))
+        .unwrap_or(text);
+    let version = Regex::new(r"\b(\d\.\d)\b")
+        .ok()
+        .and_then(|re| re.captures(usb_part))
+        .map(|cap| cap[1].to_string());
+    
+    (connector.map(|c| c.to_string()), version)
+}
+
+fn parse_yes_no(text: &str) -> Option<bool> {
+    let lower = text.trim().to_lowercase();
+    
+    if lower.starts_with("yes") {
+        Some(true)
+    } else if lower.starts_with("no") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    #[test]
+    fn band_lines() {
+        assert_eq!(parse_band_line("GSM 850 / 900 - SIM 1 & SIM 2"), ["GSM 850", "GSM 900"]);
+        assert_eq!(parse_band_line("1, 3, 28 SA/NSA - International"), ["1", "3", "28"]);
+    }
+    
+    #[test]
+    fn wifi_generations() {
+        assert_eq!(parse_wifi_standard("Wi-Fi 802.11 a/b/g/n/ac/6e, tri-band").as_deref(), Some("Wi-Fi 6E"));
+        assert_eq!(parse_wifi_standard("Wi-Fi 802.11 a/b/g/n/ac, dual-band").as_deref(), Some("Wi-Fi 5"));
+        assert_eq!(parse_wifi_standard("Wi-Fi 802.11 b/g/n").as_deref(), Some("Wi-Fi 4"));
+    }
+    
+    #[test]
+    fn usb_connector_and_version() {
+        assert_eq!(parse_usb("USB Type-C 3.2, DisplayPort 1.2, OTG"), (Some("USB Type-C".to_string()), Some("3.2".to_string())));
+        assert_eq!(parse_usb("microUSB 2.0, OTG"), (Some("microUSB".to_string()), Some("2.0".to_string())));
+        assert_eq!(parse_usb("Lightning, USB 2.0"), (Some("Lightning".to_string()), Some("2.0".to_string())));
+    }
+    
+    #[test]
+    fn network_comms_and_features() {
+        let sheet = SpecSheet::from_rows(&[
+            ("Network", "Technology", "GSM / HSPA / LTE / 5G"),
+            ("Network", "5G bands", "1, 3, 28, 78 SA/NSA - International"),
+            ("Comms", "WLAN", "Wi-Fi 802.11 a/b/g/n/ac/6e, tri-band"),
+            ("Comms", "Bluetooth", "5.3, A2DP, LE"),
+            ("Comms", "Positioning", "GPS, GALILEO, GLONASS, BDS, QZSS"),
+            ("Comms", "NFC", "Yes"),
+            ("Comms", "Radio", "No"),
+            ("Comms", "USB", "USB Type-C 3.2, DisplayPort 1.2, OTG"),
+            ("Sound", "3.5mm jack", "No"),
+            ("Features", "Sensors", "Fingerprint (under display, ultrasonic), accelerometer, gyro, proximity, compass, barometer"),
+        ]);
+        let mut details = empty_details();
+        extract(&sheet, &mut details);
+        
+        let connectivity = &details.connectivity;
+        assert!(connectivity.has_2g && connectivity.has_3g && connectivity.has_4g && connectivity.has_5g);
+        assert_eq!(connectivity.bands_5g, ["1", "3", "28", "78"]);
+        assert_eq!(connectivity.bluetooth_version.as_deref(), Some("5.3"));
+        assert_eq!(connectivity.positioning.len(), 5);
+        assert_eq!(connectivity.nfc, Some(true));
+        assert_eq!(connectivity.fm_radio, Some(false));
+        assert_eq!(connectivity.infrared, Some(false));
+        assert_eq!(connectivity.headphone_jack, Some(false));
+        assert_eq!(connectivity.sensors[0], "Fingerprint (under display, ultrasonic)");
+        assert_eq!(connectivity.sensors.len(), 6);
+    }
+}
@@ -0,0 +1,398 @@
+use regex::Regex;
+
+use crate::models::{DisplayGeometry, DisplayPanel, DisplayRole, PhoneDetails};
+use crate::specs::SpecSheet;
+use crate::utils;
+
+// Diferença relativa aceitável entre a área calculada e a informada; acima disso
+// cantos arredondados/notch tornam o cálculo pelo retângulo enganoso
+pub const AREA_TOLERANCE_PCT: f64 = 3.0;
+
+// Seção "Display": linhas Type e Protection
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    if let Some(display_type) = sheet.value("Display", "Type") {
+        details.display_type = parse_panel(&display_type);
+        details.refresh_rate_hz = parse_refresh_rate(&display_type);
+        details.hdr_formats = parse_hdr_formats(&display_type);
+        
+        let (typical, peak) = parse_brightness(&display_type);
+        details.brightness_typical_nits = typical;
+        details.brightness_peak_nits = peak;
+    }
+    
+    details.display_protection = sheet.value("Display", "Protection")
+        .and_then(|text| parse_protection(&text));
+    
+    details.displays = parse_displays(sheet);
+}
+
+// Tela principal (linhas Size/Resolution) + telas extras descritas nas linhas sem rótulo
+// (ex: "Cover display: Dynamic AMOLED 2X, 120Hz, 6.3 inches, 968 x 2376 pixels, 21:9 ratio, 410 ppi")
+pub fn parse_displays(sheet: &SpecSheet) -> Vec<DisplayPanel> {
+    let mut displays = Vec::new();
+    
+    let main_text = [sheet.value("Display", "Size"), sheet.value("Display", "Resolution")]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+    
+    if !main_text.is_empty() {
+        displays.push(parse_display_spec(DisplayRole::Main, &main_text));
+    }
+    
+    let extra_text = sheet.section("Display")
+        .filter(|row| row.label.is_empty())
+        .map(|row| row.lines.join(", "))
+        .collect::<Vec<_>>()
+        .join(", ");
+    
+    let Ok(heading_re) = Regex::new(r"(?i)(cover|external|outer|second(?:ary)?|rear|sub)[\s-]*display\s*:") else {
+        return displays;
+    };
+    
+    let headings: Vec<_> = heading_re.captures_iter(&extra_text).collect();
+    for (i, cap) in headings.iter().enumerate() {
+        let whole = cap.get(0).unwrap();
+        let end = headings.get(i + 1)
+            .map(|next| next.get(0).unwrap().start())
+            .unwrap_or(extra_text.len());
+        
+        let role = match cap[1].to_lowercase().as_str() {
+            "cover" | "external" | "outer" => DisplayRole::Cover,
+            _ => DisplayRole::Secondary,
+        };
+        
+        let panel = parse_display_spec(role, &extra_text[whole.end()..end]);
+        if panel.size_inches.is_some() || panel.width_px.is_some() {
+            displays.push(panel);
+        }
+    }
+    
+    displays
+}
+
+pub fn parse_display_spec(role: DisplayRole, text: &str) -> DisplayPanel {
+    let mut panel = DisplayPanel::new(role);
+    
+    if let Some(cap) = Regex::new(r"(?i)(\d+\.?\d*)\s*(?:inches|inch|\x22)").ok().and_then(|re| re.captures(text)) {
+        panel.size_inches = cap[1].parse().ok();
+    }
+    
+    if let Some(cap) = Regex::new(r"(\d+)\s*x\s*(\d+)\s*pixels").ok().and_then(|re| re.captures(text)) {
+        panel.width_px = cap[1].parse().ok();
+        panel.height_px = cap[2].parse().ok();
+    }
+    
+    if let Some(cap) = Regex::new(r"(\d+\.?\d*\s*:\s*\d+\.?\d*)\s*ratio").ok().and_then(|re| re.captures(text)) {
+        panel.ratio = Some(cap[1].replace(' ', ""));
+    }
+    
+    if let Some(cap) = Regex::new(r"(?i)(\d+)\s*ppi").ok().and_then(|re| re.captures(text)) {
+        panel.ppi = cap[1].parse().ok();
+    }
+    
+    // Linha Size: "6.2 inches, 94.4 cm2 (~90.9% screen-to-body ratio)"
+    if let Some(cap) = Regex::new(r"(?i)(\d+\.?\d*)\s*cm2").ok().and_then(|re| re.captures(text)) {
+        panel.reported_area_cm2 = cap[1].parse().ok();
+    }
+    
+    if let Some(cap) = Regex::new(r"(?i)~?(\d+\.?\d*)%\s*screen-to-body").ok().and_then(|re| re.captures(text)) {
+        panel.reported_screen_to_body = cap[1].parse().ok();
+    }
+    
+    refresh_area(&mut panel);
+    
+    panel
+}
+
+// Área calculada e discrepância contra a informada; refeito quando tamanho/resolução mudam
+pub fn refresh_area(panel: &mut DisplayPanel) {
+    // Sem proporção declarada (comum nas telas externas), usar a própria resolução
+    let ratio_text = panel.ratio.clone().or_else(|| match (panel.width_px, panel.height_px) {
+        (Some(w), Some(h)) => Some(format!("{}:{}", w, h)),
+        _ => None,
+    });
+    
+    panel.area_cm2 = match (panel.size_inches, ratio_text) {
+        (Some(size), Some(ratio)) => utils::calculate_display_area(&size.to_string(), &ratio),
+        _ => None,
+    };
+    
+    panel.area_discrepancy_cm2 = None;
+    panel.area_mismatch = false;
+    
+    if let (Some(computed), Some(reported)) = (panel.area_cm2, panel.reported_area_cm2)
+        && reported > 0.0 {
+        let discrepancy = computed - reported;
+        panel.area_discrepancy_cm2 = Some(discrepancy);
+        panel.area_mismatch = discrepancy.abs() / reported * 100.0 > AREA_TOLERANCE_PCT;
+    }
+}
+
+// Após correção manual dos campos legados, a tela principal segue os novos valores
+pub fn sync_main_panel(details: &mut PhoneDetails) {
+    let size = details.screen_size.as_deref().and_then(|s| {
+        let number: String = s.trim().chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect();
+        number.parse::<f64>().ok()
+    });
+    let resolution = details.resolution.as_deref().and_then(|r| {
+        let cap = Regex::new(r"(\d+)\s*x\s*(\d+)").ok()?.captures(r)?;
+        Some((cap[1].parse().ok(), cap[2].parse().ok()))
+    });
+    let ratio = details.display_ratio.clone();
+    let os_family = details.platform.os_family.clone();
+    
+    let Some(panel) = details.displays.iter_mut().find(|d| d.role == DisplayRole::Main) else {
+        return;
+    };
+    
+    if size.is_some() {
+        panel.size_inches = size;
+    }
+    if let Some((w, h)) = resolution {
+        panel.width_px = w;
+        panel.height_px = h;
+    }
+    if ratio.is_some() {
+        panel.ratio = ratio;
+    }
+    
+    refresh_area(panel);
+    panel.geometry = compute_geometry(panel, os_family.as_deref());
+}
+
+// Roda depois da seção Platform: a DPR depende do sistema operacional
+pub fn apply_geometry(details: &mut PhoneDetails) {
+    let os_family = details.platform.os_family.clone();
+    
+    for panel in &mut details.displays {
+        panel.geometry = compute_geometry(panel, os_family.as_deref());
+    }
+}
+
+pub fn compute_geometry(panel: &DisplayPanel, os_family: Option<&str>) -> Option<DisplayGeometry> {
+    let diagonal = panel.size_inches.filter(|d| *d > 0.0)?;
+    let (a, b) = (panel.width_px? as f64, panel.height_px? as f64);
+    let (short_px, long_px) = if a <= b { (a, b) } else { (b, a) };
+    
+    let diagonal_px = (short_px * short_px + long_px * long_px).sqrt();
+    if diagonal_px <= 0.0 {
+        return None;
+    }
+    
+    let width_in = diagonal * short_px / diagonal_px;
+    let height_in = diagonal * long_px / diagonal_px;
+    let ppi = diagonal_px / diagonal;
+    let dpr = estimate_dpr(ppi, os_family);
+    
+    Some(DisplayGeometry {
+        width_mm: width_in * 25.4,
+        height_mm: height_in * 25.4,
+        width_in,
+        height_in,
+        pixel_pitch_mm: 25.4 / ppi,
+        device_pixel_ratio: dpr,
+        css_width: (short_px / dpr).round() as u32,
+        css_height: (long_px / dpr).round() as u32,
+    })
+}
+
+// iOS usa escalas inteiras (@1x/@2x/@3x); Android segue densidade/160 em passos de 1/8
+// (416 ppi -> 2.625, como Pixel/Galaxy 1080p)
+pub fn estimate_dpr(ppi: f64, os_family: Option<&str>) -> f64 {
+    let os = os_family.unwrap_or_default().to_lowercase();
+    
+    if os.contains("ios") {
+        return if ppi > 400.0 {
+            3.0
+        } else if ppi > 200.0 {
+            2.0
+        } else {
+            1.0
+        };
+    }
+    
+    ((ppi / 160.0 * 8.0).round() / 8.0).max(1.0)
+}
+
+// Primeiro trecho da linha Type que não é taxa, HDR ou brilho
+// (ex: "Dynamic LTPO AMOLED 2X, 120Hz, HDR10+, 2600 nits (peak)" -> "Dynamic LTPO AMOLED 2X")
+pub fn parse_panel(text: &str) -> Option<String> {
+    text.split(',')
+        .map(|part| part.trim())
+        .find(|part| {
+            let lower = part.to_lowercase();
+            !part.is_empty()
+                && !lower.contains("hz")
+                && !lower.contains("nits")
+                && !lower.contains("hdr")
+                && !lower.contains("dolby")
+                && !lower.contains("colors")
+        })
+        .map(|part| part.to_string())
+}
+
+// Maior taxa citada (ex: "1-120Hz" ou "60/120Hz" -> 120)
+pub fn parse_refresh_rate(text: &str) -> Option<u32> {
+    let re = Regex::new(r"(?i)(\d+)\s*hz").ok()?;
+    
+    re.captures_iter(text)
+        .filter_map(|cap| cap[1].parse().ok())
+        .max()
+}
+
+pub fn parse_hdr_formats(text: &str) -> Vec<String> {
+    let Ok(re) = Regex::new(r"(?i)\b(HDR10\+|HDR10|HDR Vivid|Dolby Vision|HLG)") else {
+        return Vec::new();
+    };
+    
+    let mut formats: Vec<String> = Vec::new();
+    for cap in re.captures_iter(text) {
+        let format = cap[1].to_string();
+        if !formats.iter().any(|f| f.eq_ignore_ascii_case(&format)) {
+            formats.push(format);
+        }
+    }
+    
+    formats
+}
+
+// Retorna (típico, pico). "(HBM)" conta como pico quando não há "(peak)"
+pub fn parse_brightness(text: &str) -> (Option<u32>, Option<u32>) {
+    let Ok(re) = Regex::new(r"(?i)(\d+)\s*nits(?:\s*\(([^)]+)\))?") else {
+        return (None, None);
+    };
+    
+    let mut typical = None;
+    let mut peak = None;
+    let mut hbm = None;
+    
+    for cap in re.captures_iter(text) {
+        let Ok(nits) = cap[1].parse::<u32>() else {
+            continue;
+        };
+        let qualifier = cap.get(2).map(|m| m.as_str().to_lowercase()).unwrap_or_default();
+        
+        if qualifier.contains("peak") {
+            peak = peak.max(Some(nits));
+        } else if qualifier.contains("hbm") {
+            hbm = hbm.max(Some(nits));
+        } else if typical.is_none() {
+            typical = Some(nits);
+        }
+    }
+    
+    (typical, peak.or(hbm))
+}
+
+// Normaliza o nome da proteção (ex: "Corning Gorilla Glass Victus 2" -> "Gorilla Glass Victus 2")
+pub fn parse_protection(text: &str) -> Option<String> {
+    let first = text.split(',').next()?.trim();
+    let lower = first.to_lowercase();
+    
+    if first.is_empty() || lower == "no" || lower.starts_with("mohs") {
+        return None;
+    }
+    
+    if lower.contains("ceramic shield") {
+        return Some("Ceramic Shield".to_string());
+    }
+    
+    Some(first.trim_start_matches("Corning").trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    #[test]
+    fn type_row_fields() {
+        let text = "Dynamic LTPO AMOLED 2X, 120Hz, HDR10+, 2600 nits (peak)";
+        
+        assert_eq!(parse_panel(text).as_deref(), Some("Dynamic LTPO AMOLED 2X"));
+        assert_eq!(parse_refresh_rate(text), Some(120));
+        assert_eq!(parse_hdr_formats(text), ["HDR10+"]);
+        assert_eq!(parse_brightness(text), (None, Some(2600)));
+    }
+    
+    #[test]
+    fn typical_and_hbm_brightness() {
+        let text = "IPS LCD, 90Hz, 450 nits (typ), 600 nits (HBM)";
+        
+        assert_eq!(parse_panel(text).as_deref(), Some("IPS LCD"));
+        assert_eq!(parse_brightness(text), (Some(450), Some(600)));
+    }
+    
+    #[test]
+    fn protection_names() {
+        assert_eq!(parse_protection("Corning Gorilla Glass Victus 2").as_deref(), Some("Gorilla Glass Victus 2"));
+        assert_eq!(parse_protection("Ceramic Shield glass").as_deref(), Some("Ceramic Shield"));
+        assert_eq!(parse_protection("Mohs level 6"), None);
+    }
+    
+    #[test]
+    fn main_and_cover_displays() {
+        let sheet = SpecSheet::from_rows(&[
+            ("Display", "Type", "Foldable Dynamic LTPO AMOLED 2X, 120Hz"),
+            ("Display", "Size", "7.6 inches, 185.2 cm2 (~89.0% screen-to-body ratio)"),
+            ("Display", "Resolution", "1856 x 2160 pixels (~373 ppi density)"),
+            ("Display", "", "Cover display:\nDynamic AMOLED 2X, 120Hz, 6.3 inches, 968 x 2376 pixels, 21:9 ratio, 410 ppi"),
+        ]);
+        let displays = parse_displays(&sheet);
+        
+        assert_eq!(displays.len(), 2);
+        assert_eq!(displays[0].role, DisplayRole::Main);
+        assert_eq!(displays[0].size_inches, Some(7.6));
+        assert_eq!((displays[0].width_px, displays[0].height_px), (Some(1856), Some(2160)));
+        
+        let cover = &displays[1];
+        assert_eq!(cover.role, DisplayRole::Cover);
+        assert_eq!(cover.size_inches, Some(6.3));
+        assert_eq!(cover.ratio.as_deref(), Some("21:9"));
+        assert_eq!(cover.ppi, Some(410));
+        assert!(cover.area_cm2.is_some());
+    }
+    
+    #[test]
+    fn reported_area_cross_check() {
+        let panel = parse_display_spec(DisplayRole::Main,
+            "6.2 inches, 94.4 cm2 (~90.9% screen-to-body ratio), 1080 x 2340 pixels, 19.5:9 ratio");
+        
+        assert_eq!(panel.reported_area_cm2, Some(94.4));
+        assert_eq!(panel.reported_screen_to_body, Some(90.9));
+        assert!(panel.area_discrepancy_cm2.unwrap().abs() < 0.1);
+        assert!(!panel.area_mismatch);
+        
+        let notched = parse_display_spec(DisplayRole::Main, "6.1 inches, 80.0 cm2, 1080 x 2340 pixels, 19.5:9 ratio");
+        assert!(notched.area_mismatch);
+    }
+    
+    #[test]
+    fn geometry_and_css_viewport() {
+        let panel = parse_display_spec(DisplayRole::Main, "6.2 inches, 1080 x 2340 pixels, 19.5:9 ratio, 416 ppi");
+        let geometry = compute_geometry(&panel, Some("Android")).unwrap();
+        
+        assert_eq!(geometry.device_pixel_ratio, 2.625);
+        assert_eq!((geometry.css_width, geometry.css_height), (411, 891));
+        assert!((geometry.width_mm - 65.99).abs() < 0.01);
+        
+        assert_eq!(estimate_dpr(460.0, Some("iOS")), 3.0);
+    }
+    
+    #[test]
+    fn override_resyncs_main_panel() {
+        let mut details = empty_details();
+        details.displays = vec![parse_display_spec(DisplayRole::Main, "6.2 inches, 94.4 cm2, 1080 x 2340 pixels, 19.5:9 ratio")];
+        details.display_ratio = Some("19.5:9".to_string());
+        details.screen_size = Some("6.7\"".to_string());
+        
+        sync_main_panel(&mut details);
+        
+        let main = &details.displays[0];
+        assert_eq!(main.size_inches, Some(6.7));
+        assert!(main.area_mismatch);
+        assert!(main.geometry.is_some());
+    }
+}
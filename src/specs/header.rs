@@ -0,0 +1,120 @@
+use regex::Regex;
+use select::document::Document;
+use select::predicate::{Class, Name, Predicate};
+
+use crate::models::{EngagementStats, PhoneDetails, PopularityTrend};
+
+// Cabeçalho da página: hits, popularidade, fãs e link das opiniões
+pub fn extract(html: &str, details: &mut PhoneDetails) {
+    let document = Document::from(html);
+    let mut stats = EngagementStats::default();
+    
+    if let Some(li) = document.find(Name("li").and(Class("help-popularity"))).next() {
+        let text = li.text();
+        
+        stats.hits = Regex::new(r"([\d,]+)\s*hits")
+            .ok()
+            .and_then(|re| re.captures(&text))
+            .and_then(|cap| cap[1].replace(',', "").parse().ok());
+        stats.popularity_percent = Regex::new(r"(\d+(?:\.\d+)?)\s*%")
+            .ok()
+            .and_then(|re| re.captures(&text))
+            .and_then(|cap| cap[1].parse().ok());
+        
+        // A tendência vem na classe do <strong> (accent-popularity-up / -down)
+        stats.trend = li.find(Name("strong")).next()
+            .and_then(|strong| strong.attr("class"))
+            .map(parse_trend);
+    }
+    
+    if let Some(li) = document.find(Name("li").and(Class("help-fans"))).next() {
+        stats.fans = li.find(Name("strong")).next()
+            .and_then(|strong| strong.text().trim().replace(',', "").parse().ok());
+    }
+    
+    // Opiniões de usuários: "-reviews-ID.php" (a review editorial é "-review-")
+    if let Some(link) = document.find(Name("a")).find(|a| a.attr("href").is_some_and(|h| h.contains("-reviews-"))) {
+        stats.opinions_url = link.attr("href").map(|h| h.to_string());
+    }
+    
+    stats.review_url = find_review_url(&document);
+    
+    let text = document.find(Name("body")).next()
+        .map(|body| body.text())
+        .unwrap_or_default();
+    stats.opinions_count = Regex::new(r"(?i)total user opinions:\s*([\d,]+)")
+        .ok()
+        .and_then(|re| re.captures(&text))
+        .or_else(|| Regex::new(r"(?i)opinions\s*\(([\d,]+)\)").ok().and_then(|re| re.captures(&text)))
+        .and_then(|cap| cap[1].replace(',', "").parse().ok());
+    
+    details.engagement = stats;
+}
+
+// Review editorial: "-review-NNNN.php" (não confundir com "-reviews-", as opiniões)
+// Só o botão "Review" do cabeçalho conta; a barra lateral traz reviews de outros aparelhos
+pub fn find_review_url(document: &Document) -> Option<String> {
+    document.find(Class("article-info-meta-link-review").descendant(Name("a")))
+        .filter_map(|a| a.attr("href"))
+        .find(|href| href.contains("-review-"))
+        .map(|href| href.to_string())
+}
+
+fn parse_trend(class: &str) -> PopularityTrend {
+    if class.contains("up") {
+        PopularityTrend::Up
+    } else if class.contains("down") {
+        PopularityTrend::Down
+    } else {
+        PopularityTrend::Stable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    const HEADER: &str = r##"<html><body>
+<ul class="specs-spotlight-features">
+<li class="light pattern help help-popularity">
+<strong class="accent accent-popularity-up"><i class="head-icon icon-popularity"></i>29%</strong>
+<span>12,345,678 hits</span>
+</li>
+<li class="light pattern help help-fans">
+<a href="#" class="specs-fans"><strong class="accent"><i class="head-icon icon-favorite-b"></i>1,234</strong><span>Become a fan</span></a>
+</li>
+</ul>
+<li class="article-info-meta-link article-info-meta-link-review"><a href="samsung_galaxy_s24-review-2664.php">Review</a></li>
+<a href="samsung_galaxy_s24-reviews-12773.php">Read all opinions</a>
+<div>Total user opinions: 1,021</div>
+<aside><a href="apple_iphone_15-review-2601.php">iPhone 15 review</a></aside>
+</body></html>"##;
+    
+    #[test]
+    fn popularity_fans_and_opinions() {
+        let mut details = empty_details();
+        extract(HEADER, &mut details);
+        
+        let stats = &details.engagement;
+        assert_eq!(stats.hits, Some(12_345_678));
+        assert_eq!(stats.popularity_percent, Some(29.0));
+        assert_eq!(stats.trend, Some(PopularityTrend::Up));
+        assert_eq!(stats.fans, Some(1234));
+        assert_eq!(stats.opinions_count, Some(1021));
+        assert_eq!(stats.opinions_url.as_deref(), Some("samsung_galaxy_s24-reviews-12773.php"));
+    }
+    
+    #[test]
+    fn review_link_prefers_header_button() {
+        let document = Document::from(HEADER);
+        assert_eq!(find_review_url(&document).as_deref(), Some("samsung_galaxy_s24-review-2664.php"));
+    }
+    
+    #[test]
+    fn sidebar_reviews_are_not_the_device_review() {
+        let html = HEADER.replace(r#"<li class="article-info-meta-link article-info-meta-link-review"><a href="samsung_galaxy_s24-review-2664.php">Review</a></li>"#, "");
+        let document = Document::from(html.as_str());
+        assert_eq!(find_review_url(&document), None);
+    }
+}
@@ -0,0 +1,164 @@
+use regex::Regex;
+
+use crate::models::{BenchmarkScore, EuLabel, PhoneDetails, TestResults};
+use crate::specs::SpecSheet;
+
+// Seções "EU LABEL" (etiqueta energética) e "Tests" (medições do GSMArena)
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let mut tests = TestResults::default();
+    
+    let (Ok(contrast_re), Ok(nits_re), Ok(lufs_re)) = (
+        Regex::new(r"(?i)contrast ratio:\s*([^,(\n]+)"),
+        Regex::new(r"(?i)(\d+)\s*nits"),
+        Regex::new(r"(?i)(-?\d+(?:\.\d+)?)\s*LUFS\s*(?:\(([^)]+)\))?"),
+    ) else {
+        return;
+    };
+    
+    if sheet.section("EU LABEL").next().is_some() {
+        tests.eu_label = Some(parse_eu_label(sheet));
+    }
+    
+    for row in sheet.section("Tests") {
+        let label = row.label.to_lowercase();
+        
+        if label.starts_with("performance") {
+            tests.benchmarks = row.lines.iter()
+                .flat_map(|line| line.split(" / "))
+                .filter_map(parse_benchmark)
+                .collect();
+        } else if label.starts_with("display") {
+            let text = row.value();
+            tests.contrast_ratio = contrast_re.captures(&text)
+                .map(|cap| cap[1].trim().to_string());
+            tests.measured_brightness_nits = nits_re.captures(&text)
+                .and_then(|cap| cap[1].parse().ok());
+        } else if label.starts_with("loudspeaker") {
+            let text = row.value();
+            if let Some(cap) = lufs_re.captures(&text) {
+                tests.loudspeaker_lufs = cap[1].parse().ok();
+                tests.loudspeaker_rating = cap.get(2).map(|m| m.as_str().to_string());
+            }
+        } else if label.starts_with("battery") {
+            let text = row.value();
+            let lower = text.to_lowercase();
+            
+            if lower.contains("active use") {
+                tests.battery_active_use_hours = parse_hours(&text);
+            } else if lower.contains("endurance") {
+                tests.battery_endurance_hours = parse_hours(&text);
+            }
+        }
+    }
+    
+    details.tests = tests;
+}
+
+pub fn parse_eu_label(sheet: &SpecSheet) -> EuLabel {
+    let mut label = EuLabel {
+        energy_class: sheet.value("EU LABEL", "Energy").and_then(|text| parse_class(&text)),
+        repairability_class: sheet.value("EU LABEL", "Repairability").and_then(|text| parse_class(&text)),
+        ..EuLabel::default()
+    };
+    
+    if let Some(text) = sheet.value("EU LABEL", "Battery") {
+        label.battery_endurance_hours = parse_hours(&text);
+        label.battery_cycles = capture_u32(r"(?i)(\d+)\s*cycles", &text);
+    }
+    
+    if let Some(text) = sheet.value("EU LABEL", "Free fall") {
+        label.free_fall_class = parse_class(&text);
+        label.free_fall_count = capture_u32(r"(?i)(\d+)\s*falls", &text);
+    }
+    
+    label
+}
+
+// "AnTuTu: 1713274 (v10)" / "3DMark: 4092 (Wild Life Extreme)"
+pub fn parse_benchmark(line: &str) -> Option<BenchmarkScore> {
+    let cap = Regex::new(r"^\s*([^:]+?):\s*([\d,.]+)\s*(?:\(([^)]+)\))?")
+        .ok()?
+        .captures(line)?;
+    
+    Some(BenchmarkScore {
+        name: cap[1].to_string(),
+        score: cap[2].replace(',', "").parse().ok()?,
+        variant: cap.get(3).map(|m| m.as_str().to_string()),
+    })
+}
+
+// "12:57h" -> 12.95, "Endurance rating 111h" -> 111.0
+pub fn parse_hours(text: &str) -> Option<f64> {
+    let re = Regex::new(r"(\d+)(?::(\d{2}))?\s*h\b").ok()?;
+    let cap = re.captures(text)?;
+    
+    let hours: f64 = cap[1].parse().ok()?;
+    let minutes: f64 = cap.get(2).and_then(|m| m.as_str().parse().ok()).unwrap_or(0.0);
+    
+    Some(hours + minutes / 60.0)
+}
+
+// "Class B (180 falls)" -> "B"
+fn parse_class(text: &str) -> Option<String> {
+    Regex::new(r"(?i)class\s+([A-G]\+*)")
+        .ok()?
+        .captures(text)
+        .map(|cap| cap[1].to_uppercase())
+}
+
+fn capture_u32(pattern: &str, text: &str) -> Option<u32> {
+    Regex::new(pattern)
+        .ok()?
+        .captures(text)
+        .and_then(|cap| cap[1].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    #[test]
+    fn benchmark_lines() {
+        let antutu = parse_benchmark("AnTuTu: 1713274 (v10)").unwrap();
+        assert_eq!((antutu.name.as_str(), antutu.score, antutu.variant.as_deref()), ("AnTuTu", 1713274.0, Some("v10")));
+        
+        let mark = parse_benchmark("3DMark: 4092 (Wild Life Extreme)").unwrap();
+        assert_eq!(mark.variant.as_deref(), Some("Wild Life Extreme"));
+    }
+    
+    #[test]
+    fn hours() {
+        assert!((parse_hours("12:57h").unwrap() - 12.95).abs() < 1e-9);
+        assert_eq!(parse_hours("Endurance rating 111h"), Some(111.0));
+    }
+    
+    #[test]
+    fn eu_label_and_tests_sections() {
+        let sheet = SpecSheet::from_rows(&[
+            ("EU LABEL", "Energy", "Class A"),
+            ("EU LABEL", "Battery", "82:52h endurance, 1200 cycles"),
+            ("EU LABEL", "Free fall", "Class B (180 falls)"),
+            ("EU LABEL", "Repairability", "Class C"),
+            ("Tests", "Performance", "AnTuTu: 1713274 (v10)\n3DMark: 4092 (Wild Life Extreme)"),
+            ("Tests", "Display", "Contrast ratio: Infinite (nominal), 1498 nits (measured)"),
+            ("Tests", "Loudspeaker", "-25.5 LUFS (Very good)"),
+            ("Tests", "Battery (new)", "Active use score 14:20h"),
+        ]);
+        let mut details = empty_details();
+        extract(&sheet, &mut details);
+        
+        let label = details.tests.eu_label.as_ref().unwrap();
+        assert_eq!(label.energy_class.as_deref(), Some("A"));
+        assert_eq!(label.battery_cycles, Some(1200));
+        assert_eq!((label.free_fall_class.as_deref(), label.free_fall_count), (Some("B"), Some(180)));
+        assert_eq!(label.repairability_class.as_deref(), Some("C"));
+        
+        assert_eq!(details.tests.benchmarks.len(), 2);
+        assert_eq!(details.tests.contrast_ratio.as_deref(), Some("Infinite"));
+        assert_eq!(details.tests.measured_brightness_nits, Some(1498));
+        assert_eq!(details.tests.loudspeaker_lufs, Some(-25.5));
+        assert_eq!(details.tests.loudspeaker_rating.as_deref(), Some("Very good"));
+        assert!(details.tests.battery_active_use_hours.is_some());
+    }
+}
@@ -0,0 +1,161 @@
+use regex::Regex;
+
+use crate::models::{LifecycleStatus, PartialDate, PhoneDetails};
+use crate::specs::SpecSheet;
+
+// Seção "Launch": Announced e Status (com a data de lançamento embutida)
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let announced = sheet.value("Launch", "Announced").unwrap_or_default();
+    let status = sheet.value("Launch", "Status").unwrap_or_default();
+    
+    // Páginas antigas trazem "2019, September 10. Released 2019, September 20" no Announced
+    let (announced_text, released_in_announced) = split_released(&announced);
+    let (_, released_in_status) = split_released(&status);
+    
+    details.announced = parse_partial_date(announced_text);
+    details.released = released_in_status
+        .or(released_in_announced)
+        .and_then(parse_partial_date);
+    details.lifecycle_status = parse_status(&status);
+}
+
+// Linha de onde sai a data de lançamento: Status nas páginas novas, Announced nas antigas
+pub fn released_label(sheet: &SpecSheet) -> Option<&'static str> {
+    let status = sheet.value("Launch", "Status").unwrap_or_default();
+    if split_released(&status).1.is_some() {
+        return Some("Status");
+    }
+    
+    let announced = sheet.value("Launch", "Announced").unwrap_or_default();
+    split_released(&announced).1.map(|_| "Announced")
+}
+
+pub fn parse_status(text: &str) -> Option<LifecycleStatus> {
+    let lower = text.to_lowercase();
+    
+    if lower.contains("cancelled") || lower.contains("canceled") {
+        Some(LifecycleStatus::Cancelled)
+    } else if lower.contains("discontinued") {
+        Some(LifecycleStatus::Discontinued)
+    } else if lower.contains("coming soon") {
+        Some(LifecycleStatus::ComingSoon)
+    } else if lower.contains("rumored") || lower.contains("rumoured") {
+        Some(LifecycleStatus::Rumored)
+    } else if lower.contains("available") {
+        Some(LifecycleStatus::Available)
+    } else {
+        None
+    }
+}
+
+// Aceita "2021, March 17", "2021, Mar", "2025, Q3", "2014, 1Q", "Exp. release 2025, Q3" e "2021"
+pub fn parse_partial_date(text: &str) -> Option<PartialDate> {
+    let re = Regex::new(r"(?i)\b(\d{4})(?:\s*,\s*(?:q([1-4])|([1-4])q|([a-z]{3,9})\.?(?:\s+(\d{1,2}))?))?").ok()?;
+    let cap = re.captures(text)?;
+    
+    let year: i32 = cap[1].parse().ok()?;
+    let quarter = cap.get(2)
+        .or_else(|| cap.get(3))
+        .and_then(|m| m.as_str().parse().ok());
+    let month = cap.get(4).and_then(|m| month_number(m.as_str()));
+    let day = month
+        .and(cap.get(5))
+        .and_then(|m| m.as_str().parse::<u32>().ok())
+        .filter(|d| (1..=31).contains(d));
+    
+    Some(PartialDate { year, month, day, quarter })
+}
+
+// Separa o trecho "Released ..." / "Exp. release ..." do restante do texto
+fn split_released(text: &str) -> (&str, Option<&str>) {
+    match text.find("Released").or_else(|| text.find("release")) {
+        Some(pos) => (&text[..pos], Some(&text[pos..])),
+        None => (text, None),
+    }
+}
+
+fn month_number(name: &str) -> Option<u32> {
+    let months = [
+        "jan", "feb", "mar", "apr", "may", "jun",
+        "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let lower = name.to_lowercase();
+    
+    months.iter()
+        .position(|m| lower.starts_with(m))
+        .map(|i| i as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    fn details_for(rows: &[(&str, &str, &str)]) -> PhoneDetails {
+        let mut details = empty_details();
+        extract(&SpecSheet::from_rows(rows), &mut details);
+        details
+    }
+    
+    #[test]
+    fn announced_and_released_from_status() {
+        let details = details_for(&[
+            ("Launch", "Announced", "2021, March 17"),
+            ("Launch", "Status", "Available. Released 2021, March 26"),
+        ]);
+        
+        assert_eq!(details.announced.map(|d| d.to_string()).as_deref(), Some("2021-03-17"));
+        assert_eq!(details.released.map(|d| d.to_string()).as_deref(), Some("2021-03-26"));
+        assert_eq!(details.lifecycle_status, Some(LifecycleStatus::Available));
+    }
+    
+    #[test]
+    fn cancelled_has_no_release() {
+        let details = details_for(&[("Launch", "Status", "Cancelled")]);
+        
+        assert_eq!(details.lifecycle_status, Some(LifecycleStatus::Cancelled));
+        assert_eq!(details.released, None);
+    }
+    
+    #[test]
+    fn coming_soon_with_expected_quarter() {
+        let details = details_for(&[("Launch", "Status", "Coming soon. Exp. release 2025, Q3")]);
+        
+        assert_eq!(details.lifecycle_status, Some(LifecycleStatus::ComingSoon));
+        assert_eq!(details.released, Some(PartialDate { year: 2025, month: None, day: None, quarter: Some(3) }));
+    }
+    
+    #[test]
+    fn old_pages_release_in_announced_row() {
+        let rows = [
+            ("Launch", "Announced", "2019, September 10. Released 2019, September 20"),
+            ("Launch", "Status", "Discontinued"),
+        ];
+        let details = details_for(&rows);
+        
+        assert_eq!(details.announced.map(|d| d.to_string()).as_deref(), Some("2019-09-10"));
+        assert_eq!(details.released.map(|d| d.to_string()).as_deref(), Some("2019-09-20"));
+        assert_eq!(released_label(&SpecSheet::from_rows(&rows)), Some("Announced"));
+    }
+    
+    #[test]
+    fn partial_date_variants() {
+        assert_eq!(parse_partial_date("2021, Mar"), Some(PartialDate { year: 2021, month: Some(3), day: None, quarter: None }));
+        assert_eq!(parse_partial_date("2014, 1Q"), Some(PartialDate { year: 2014, month: None, day: None, quarter: Some(1) }));
+        assert_eq!(parse_partial_date("2021"), Some(PartialDate { year: 2021, month: None, day: None, quarter: None }));
+        assert_eq!(parse_partial_date("Not announced yet"), None);
+    }
+    
+    #[test]
+    fn quarter_sorts_with_its_first_month() {
+        let mut dates = [
+            parse_partial_date("2025, September").unwrap(),
+            parse_partial_date("2025, Q3").unwrap(),
+            parse_partial_date("2025, June 2").unwrap(),
+        ];
+        dates.sort_by_key(|d| d.sort_key());
+        
+        let sorted: Vec<String> = dates.iter().map(|d| d.to_string()).collect();
+        assert_eq!(sorted, ["2025-06-02", "2025-Q3", "2025-09"]);
+    }
+}
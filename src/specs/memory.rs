@@ -0,0 +1,207 @@
+use regex::Regex;
+
+use crate::models::{MemorySpecs, MemoryVariant, PhoneDetails};
+use crate::specs::{split_top_level, SpecSheet};
+
+// Seção "Memory": Internal (combinações armazenamento/RAM), tipo de armazenamento e Card slot
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let mut memory = MemorySpecs::default();
+    
+    if let Some(text) = sheet.value("Memory", "Internal") {
+        memory.variants = parse_variants(&text);
+    }
+    
+    let all_text = sheet.section("Memory")
+        .map(|row| row.value())
+        .collect::<Vec<_>>()
+        .join("\n");
+    memory.storage_types = parse_storage_types(&all_text);
+    
+    if let Some(text) = sheet.value("Memory", "Card slot") {
+        memory.card_slot_supported = parse_card_slot(&text);
+        memory.card_slot = Some(text);
+    }
+    
+    // Campos de texto antigos: tamanhos distintos (ex: "4GB / 6GB / 8GB")
+    details.ram = distinct_sizes(memory.variants.iter().filter_map(|v| v.ram_gb));
+    details.storage = distinct_sizes(memory.variants.iter().filter_map(|v| v.storage_gb));
+    details.memory = memory;
+}
+
+// "128GB 4GB RAM, 128GB 6GB RAM, 256GB 8GB RAM" / "1TB 12GB RAM" / "512MB 256MB RAM"
+// Formato antigo "16/32 GB, 2 GB RAM" vira uma combinação por armazenamento
+pub fn parse_variants(text: &str) -> Vec<MemoryVariant> {
+    let (Ok(storage_re), Ok(ram_re)) = (
+        Regex::new(r"(?i)((?:\d+(?:\.\d+)?\s*/\s*)*\d+(?:\.\d+)?)\s*(TB|GB|MB)\b"),
+        Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*(GB|MB)\s*RAM"),
+    ) else {
+        return Vec::new();
+    };
+    
+    let mut variants = Vec::new();
+    let mut loose_storages = Vec::new();
+    let mut loose_rams = Vec::new();
+    
+    for part in split_top_level(text, ',') {
+        let ram_gb = ram_re.captures(&part)
+            .and_then(|cap| to_gb(&cap[1], &cap[2]));
+        
+        // O armazenamento é o tamanho que não faz parte do "xGB RAM"
+        let without_ram = ram_re.replace_all(&part, "");
+        let storages: Vec<f64> = storage_re.captures(&without_ram)
+            .map(|cap| {
+                cap[1].split('/')
+                    .filter_map(|value| to_gb(value.trim(), &cap[2]))
+                    .collect()
+            })
+            .unwrap_or_default();
+        
+        match (storages.is_empty(), ram_gb) {
+            (false, Some(_)) => {
+                for storage in storages {
+                    variants.push(MemoryVariant { storage_gb: Some(storage), ram_gb });
+                }
+            }
+            (false, None) => loose_storages.extend(storages),
+            (true, Some(ram)) => loose_rams.push(ram),
+            (true, None) => {}
+        }
+    }
+    
+    // Armazenamento e RAM em trechos separados: combinar todos
+    match (loose_storages.is_empty(), loose_rams.is_empty()) {
+        (false, false) => {
+            for storage in &loose_storages {
+                for ram in &loose_rams {
+                    variants.push(MemoryVariant { storage_gb: Some(*storage), ram_gb: Some(*ram) });
+                }
+            }
+        }
+        (false, true) => variants.extend(loose_storages.iter().map(|s| MemoryVariant { storage_gb: Some(*s), ram_gb: None })),
+        (true, false) => variants.extend(loose_rams.iter().map(|r| MemoryVariant { storage_gb: None, ram_gb: Some(*r) })),
+        (true, true) => {}
+    }
+    
+    let mut distinct = Vec::new();
+    for variant in variants {
+        if !distinct.contains(&variant) {
+            distinct.push(variant);
+        }
+    }
+    
+    distinct
+}
+
+// "UFS 3.1 - 128GB model\nUFS 4.0 - 256GB and 512GB models" -> ["UFS 3.1", "UFS 4.0"]
+pub fn parse_storage_types(text: &str) -> Vec<String> {
+    let Ok(re) = Regex::new(r"(?i)\b(UFS\s*\d(?:\.\d)?|eMMC\s*\d(?:\.\d)?|NVMe)") else {
+        return Vec::new();
+    };
+    
+    let mut types: Vec<String> = Vec::new();
+    for cap in re.captures_iter(text) {
+        let storage_type = cap[1].to_string();
+        if !types.contains(&storage_type) {
+            types.push(storage_type);
+        }
+    }
+    
+    types
+}
+
+// "No" -> false, "microSDXC (dedicated slot)" -> true, "Unspecified" -> None
+pub fn parse_card_slot(text: &str) -> Option<bool> {
+    let lower = text.trim().to_lowercase();
+    
+    if lower.starts_with("no") {
+        Some(false)
+    } else if lower.contains("microsd") || lower.starts_with("yes") {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+fn to_gb(value: &str, unit: &str) -> Option<f64> {
+    let value: f64 = value.parse().ok()?;
+    
+    match unit.to_uppercase().as_str() {
+        "TB" => Some(value * 1024.0),
+        "GB" => Some(value),
+        "MB" => Some(value / 1024.0),
+        _ => None,
+    }
+}
+
+fn distinct_sizes(sizes: impl Iterator<Item = f64>) -> Option<String> {
+    let mut distinct: Vec<f64> = Vec::new();
+    for size in sizes {
+        if !distinct.contains(&size) {
+            distinct.push(size);
+        }
+    }
+    distinct.sort_by(|a, b| a.total_cmp(b));
+    
+    if distinct.is_empty() {
+        return None;
+    }
+    
+    Some(distinct.iter()
+        .map(|gb| format_gb(*gb))
+        .collect::<Vec<_>>()
+        .join(" / "))
+}
+
+pub fn format_gb(gb: f64) -> String {
+    if gb >= 1024.0 && gb % 1024.0 == 0.0 {
+        format!("{}TB", gb / 1024.0)
+    } else if gb < 1.0 {
+        format!("{}MB", gb * 1024.0)
+    } else {
+        format!("{}GB", gb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specs::empty_details;
+    
+    fn variant(storage_gb: f64, ram_gb: f64) -> MemoryVariant {
+        MemoryVariant { storage_gb: Some(storage_gb), ram_gb: Some(ram_gb) }
+    }
+    
+    #[test]
+    fn storage_ram_combinations() {
+        let variants = parse_variants("128GB 4GB RAM, 128GB 6GB RAM, 256GB 8GB RAM");
+        
+        assert_eq!(variants, [variant(128.0, 4.0), variant(128.0, 6.0), variant(256.0, 8.0)]);
+        assert_eq!(parse_variants("1TB 12GB RAM"), [variant(1024.0, 12.0)]);
+        assert_eq!(parse_variants("512MB 256MB RAM"), [variant(0.5, 0.25)]);
+    }
+    
+    #[test]
+    fn old_format_combines_loose_sizes() {
+        assert_eq!(parse_variants("16/32 GB, 2 GB RAM"), [variant(16.0, 2.0), variant(32.0, 2.0)]);
+    }
+    
+    #[test]
+    fn storage_type_and_card_slot() {
+        let sheet = SpecSheet::from_rows(&[
+            ("Memory", "Card slot", "microSDXC (dedicated slot)"),
+            ("Memory", "Internal", "128GB 4GB RAM, 128GB 6GB RAM, 256GB 8GB RAM"),
+            ("Memory", "", "UFS 2.2"),
+        ]);
+        let mut details = empty_details();
+        extract(&sheet, &mut details);
+        
+        assert_eq!(details.memory.storage_types, ["UFS 2.2"]);
+        assert_eq!(details.memory.card_slot_supported, Some(true));
+        assert_eq!((details.memory.min_ram_gb(), details.memory.max_ram_gb()), (Some(4.0), Some(8.0)));
+        assert_eq!(details.ram.as_deref(), Some("4GB / 6GB / 8GB"));
+        assert_eq!(details.storage.as_deref(), Some("128GB / 256GB"));
+        
+        assert_eq!(parse_card_slot("No"), Some(false));
+        assert_eq!(parse_card_slot("Unspecified"), None);
+    }
+}
@@ -0,0 +1,159 @@
+use regex::Regex;
+
+use crate::models::{ModelVersion, PhoneDetails, PriceQuote};
+use crate::specs::{split_top_level, SpecSheet};
+
+// Seção "Misc": códigos de modelo, versões regionais e preço
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    if let Some(text) = sheet.value("Misc", "Models") {
+        details.model_codes = parse_model_codes(&text);
+    }
+    
+    // "Versions" aparece fora da Misc em algumas páginas
+    if let Some(row) = sheet.rows.iter().find(|r| r.label.eq_ignore_ascii_case("Versions")) {
+        details.versions = parse_versions(&row.value());
+    }
+    
+    // Códigos que só aparecem em "Versions" também entram na lista
+    for version in &details.versions {
+        if !details.model_codes.contains(&version.code) {
+            details.model_codes.push(version.code.clone());
+        }
+    }
+    
+    if let Some(text) = sheet.value("Misc", "Price") {
+        details.prices = parse_prices(&text);
+    }
+}
+
+// "SM-A525F, SM-A525F/DS, SM-A525M"
+pub fn parse_model_codes(text: &str) -> Vec<String> {
+    let mut codes: Vec<String> = Vec::new();
+    
+    for code in text.split([',', ';', '\n']).map(str::trim).filter(|c| !c.is_empty()) {
+        if !codes.iter().any(|c| c == code) {
+            codes.push(code.to_string());
+        }
+    }
+    
+    codes
+}
+
+// "SM-A525F (Global); SM-A525M (LATAM)" ou uma versão por linha
+pub fn parse_versions(text: &str) -> Vec<ModelVersion> {
+    let Ok(re) = Regex::new(r"^([A-Za-z0-9][A-Za-z0-9/\-_.]*)\s*(?:\((.+)\))?\s*(.*)$") else {
+        return Vec::new();
+    };
+    
+    text.lines()
+        .flat_map(|line| split_top_level(line, ';'))
+        .flat_map(|part| split_top_level(&part, ','))
+        .filter_map(|part| {
+            let cap = re.captures(&part)?;
+            let regions = cap.get(2)
+                .or(cap.get(3))
+                .map(|m| m.as_str().trim().to_string())
+                .filter(|r| !r.is_empty());
+            
+            Some(ModelVersion { code: cap[1].to_string(), regions })
+        })
+        .collect()
+}
+
+// "$ 299.99 / € 279.00 / £ 249.00 / ₹ 24,999" ou "About 200 EUR"
+pub fn parse_prices(text: &str) -> Vec<PriceQuote> {
+    let Ok(re) = Regex::new(
+        r"(?:(C\$|A\$|R\$|\$|€|£|₹|¥)\s*([\d][\d,]*(?:\.\d+)?))|(?:([\d][\d,]*(?:\.\d+)?)\s*([A-Z]{3})\b)"
+    ) else {
+        return Vec::new();
+    };
+    let approximate = text.to_lowercase().contains("about");
+    
+    re.captures_iter(text)
+        .filter_map(|cap| {
+            let (currency, amount) = match (cap.get(1), cap.get(2)) {
+                (Some(symbol), Some(amount)) => (currency_from_symbol(symbol.as_str())?, amount.as_str()),
+                _ => (cap.get(4)?.as_str().to_string(), cap.get(3)?.as_str()),
+            };
+            
+            Some(PriceQuote {
+                currency,
+                amount: amount.replace(',', "").parse().ok()?,
+                approximate,
+            })
+        })
+        .collect()
+}
+
+fn currency_from_symbol(symbol: &str) -> Option<String> {
+    let code = match symbol {
+        "$" => "USD",
+        "€" => "EUR",
+        "£" => "GBP",
+        "₹" => "INR",
+        "¥" => "CNY", // O GSMArena usa ¥ para preços chineses
+        "C$" => "CAD",
+        "A$" => "AUD",
+        "R$" => "BRL",
+        _ => return None,
+    };
+    
+    Some(code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ExchangeRates;
+    use crate::specs::empty_details;
+    
+    fn quote(currency: &str, amount: f64, approximate: bool) -> PriceQuote {
+        PriceQuote { currency: currency.to_string(), amount, approximate }
+    }
+    
+    #[test]
+    fn model_codes() {
+        assert_eq!(parse_model_codes("SM-A525F, SM-A525F/DS, SM-A525M"), ["SM-A525F", "SM-A525F/DS", "SM-A525M"]);
+    }
+    
+    #[test]
+    fn versions_add_missing_codes() {
+        let sheet = SpecSheet::from_rows(&[
+            ("Misc", "Models", "SM-A525F, SM-A525F/DS"),
+            ("Misc", "Versions", "SM-A525F (Global); SM-A525M (USA, Canada)"),
+        ]);
+        let mut details = empty_details();
+        extract(&sheet, &mut details);
+        
+        assert_eq!(details.versions[1].regions.as_deref(), Some("USA, Canada"));
+        assert_eq!(details.model_codes, ["SM-A525F", "SM-A525F/DS", "SM-A525M"]);
+    }
+    
+    #[test]
+    fn price_symbols_and_codes() {
+        assert_eq!(parse_prices("$ 299.99 / € 279.00 / £ 249.00 / ₹ 24,999"), [
+            quote("USD", 299.99, false),
+            quote("EUR", 279.0, false),
+            quote("GBP", 249.0, false),
+            quote("INR", 24999.0, false),
+        ]);
+        assert_eq!(parse_prices("About 200 EUR"), [quote("EUR", 200.0, true)]);
+    }
+    
+    #[test]
+    fn conversion_prefers_base_currency() {
+        let mut details = empty_details();
+        details.prices = parse_prices("$ 299.99 / € 279.00");
+        
+        let rates = ExchangeRates {
+            base_currency: "EUR".to_string(),
+            rates: [("USD".to_string(), 0.9)].into_iter().collect(),
+        };
+        details.apply_exchange_rates(&rates);
+        assert_eq!(details.price_base, Some(quote("EUR", 279.0, false)));
+        
+        details.prices = parse_prices("About 200 USD");
+        details.apply_exchange_rates(&rates);
+        assert_eq!(details.price_base, Some(quote("EUR", 180.0, true)));
+    }
+}
@@ -0,0 +1,246 @@
+use regex::Regex;
+
+use crate::models::{Chipset, CpuCluster, CpuInfo, PhoneDetails, PlatformSpecs};
+use crate::specs::{split_top_level, SpecSheet};
+
+// Seção "Platform": OS, Chipset, CPU e GPU (uma linha por variante regional)
+pub fn extract(sheet: &SpecSheet, details: &mut PhoneDetails) {
+    let mut platform = PlatformSpecs::default();
+    
+    if let Some(os) = sheet.get("Platform", "OS") {
+        let text = os.lines.join(", ");
+        parse_os(&text, &mut platform);
+        details.os = Some(text);
+    }
+    
+    if let Some(chipset) = sheet.get("Platform", "Chipset") {
+        platform.chipsets = chipset.lines.iter()
+            .map(|line| parse_chipset(line))
+            .collect();
+        details.chipset = Some(chipset.lines.join(" / "));
+    }
+    
+    if let Some(cpu) = sheet.get("Platform", "CPU") {
+        platform.cpus = cpu.lines.iter()
+            .map(|line| parse_cpu(line))
+            .collect();
+    }
+    
+    if let Some(gpu) = sheet.get("Platform", "GPU") {
+        for line in &gpu.lines {
+            let name = parse_gpu_name(line);
+            if !name.is_empty() && !platform.gpus.contains(&name) {
+                platform.gpus.push(name);
+            }
+        }
+    }
+    
+    details.platform = platform;
+}
+
+// "Android 14, up to 7 major Android upgrades, One UI 6.1" / "iOS 17, upgradable to iOS 18.1"
+// A família vai até o primeiro dígito ou "(" ("Windows Phone 8.1", "Fire OS 8")
+pub fn parse_os(text: &str, platform: &mut PlatformSpecs) {
+    let parts = split_top_level(text, ',');
+    
+    if let Some(first) = parts.first()
+        && let Ok(re) = Regex::new(r"^([A-Za-z][^\d(]*)(\d+(?:\.\d+)*)?")
+        && let Some(cap) = re.captures(first) {
+        platform.os_family = Some(cap[1].trim().to_string());
+        platform.os_version = cap.get(2).map(|m| m.as_str().to_string());
+    }
+    
+    let upgrades_re = Regex::new(r"(\d+)\s+major").ok();
+    
+    for part in parts.iter().skip(1) {
+        let lower = part.to_lowercase();
+        
+        if let Some(rest) = lower.strip_prefix("upgradable to") {
+            platform.upgradable_to = Some(part[part.len() - rest.len()..].trim().to_string());
+        } else if lower.contains("upgrade") {
+            platform.major_upgrades = upgrades_re.as_ref()
+                .and_then(|re| re.captures(&lower))
+                .and_then(|cap| cap[1].parse().ok());
+            platform.upgrade_promise = Some(part.to_string());
+        } else if platform.os_skin.is_none() {
+            platform.os_skin = Some(part.to_string());
+        }
+    }
+}
+
+// "Qualcomm SM8650-AC Snapdragon 8 Gen 3 (4 nm) - USA/Canada/China"
+pub fn parse_chipset(line: &str) -> Chipset {
+    let (text, region) = split_region(line);
+    
+    let process_nm = Regex::new(r"\((\d+(?:\.\d+)?)\s*nm\)")
+        .ok()
+        .and_then(|re| re.captures(text))
+        .and_then(|cap| cap[1].parse().ok());
+    
+    let name = text.split('(').next().unwrap_or(text).trim();
+    let known_vendors = ["Qualcomm", "Mediatek", "MediaTek", "Apple", "Google", "Samsung", "Unisoc", "HiSilicon", "Spreadtrum", "Intel", "Nvidia", "Rockchip"];
+    
+    let (vendor, model) = match name.split_once(' ') {
+        Some((first, rest)) if known_vendors.contains(&first) => (Some(first.to_string()), rest.to_string()),
+        _ => (infer_chipset_vendor(name), name.to_string()),
+    };
+    
+    Chipset { vendor, model, process_nm, region }
+}
+
+// "Octa-core (1x3.39GHz Cortex-X4 & 3x3.1GHz Cortex-A720 & 4x2.2GHz Cortex-A520) - International"
+pub fn parse_cpu(line: &str) -> CpuInfo {
+    let (text, region) = split_region(line);
+    let lower = text.to_lowercase();
+    
+    let core_words = [
+        ("single", 1), ("dual", 2), ("tri", 3), ("quad", 4), ("hexa", 6),
+        ("octa", 8), ("nona", 9), ("deca", 10),
+    ];
+    let mut cores = core_words.iter()
+        .find(|(word, _)| lower.starts_with(&format!("{}-core", word)))
+        .map(|(_, count)| *count);
+    
+    let mut clusters = Vec::new();
+    if let Ok(re) = Regex::new(r"(?i)(\d+)\s*x\s*(\d+(?:\.\d+)?)\s*(GHz|MHz)\s*([^&+)]*)") {
+        for cap in re.captures_iter(text) {
+            let (Ok(count), Ok(clock)) = (cap[1].parse::<u32>(), cap[2].parse::<f64>()) else {
+                continue;
+            };
+            let clock_ghz = if cap[3].eq_ignore_ascii_case("mhz") { clock / 1000.0 } else { clock };
+            let core = cap[4].trim();
+            
+            clusters.push(CpuCluster {
+                count,
+                clock_ghz,
+                core: (!core.is_empty()).then(|| core.to_string()),
+            });
+        }
+    }
+    
+    // Sem a palavra "X-core", somar os clusters
+    if cores.is_none() && !clusters.is_empty() {
+        cores = Some(clusters.iter().map(|c| c.count).sum());
+    }
+    
+    // Sem clusters ("Octa-core 2.0 GHz Cortex-A53"), usar o primeiro clock citado
+    let max_clock_ghz = clusters.iter()
+        .map(|c| c.clock_ghz)
+        .fold(None, |max: Option<f64>, clock| Some(max.map_or(clock, |m| m.max(clock))))
+        .or_else(|| {
+            Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*GHz")
+                .ok()?
+                .captures(text)
+                .and_then(|cap| cap[1].parse().ok())
+        });
+    
+    CpuInfo { cores, clusters, max_clock_ghz, region }
+}
+
+// "Adreno 750 (1 GHz) - USA/Canada/China" -> "Adreno 750"
+pub fn parse_gpu_name(line: &str) -> String {
+    let (text, _) = split_region(line);
+    text.split('(').next().unwrap_or(text).trim().to_string()
+}
+
+fn infer_chipset_vendor(model: &str) -> Option<String> {
+    let lower = model.to_lowercase();
+    let vendor = if lower.contains("snapdragon") {
+        "Qualcomm"
+    } else if lower.contains("exynos") {
+        "Samsung"
+    } else if lower.contains("dimensity") || lower.contains("helio") {
+        "Mediatek"
+    } else if lower.contains("kirin") {
+        "HiSilicon"
+    } else if lower.contains("tensor") {
+        "Google"
+    } else if lower.contains("tiger") || lower.starts_with("unisoc") {
+        "Unisoc"
+    } else if lower.starts_with("apple") || Regex::new(r"^a\d+").is_ok_and(|re| re.is_match(&lower)) {
+        "Apple"
+    } else {
+        return None;
+    };
+    
+    Some(vendor.to_string())
+}
+
+// Separa o sufixo regional (" - International") do restante da linha
+fn split_region(line: &str) -> (&str, Option<String>) {
+    match line.rsplit_once(" - ") {
+        Some((text, region)) if !region.contains('(') => (text.trim(), Some(region.trim().to_string())),
+        _ => (line.trim(), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn os_with_upgrade_promise_and_skin() {
+        let mut platform = PlatformSpecs::default();
+        parse_os("Android 14, up to 7 major Android upgrades, One UI 6.1", &mut platform);
+        
+        assert_eq!(platform.os_family.as_deref(), Some("Android"));
+        assert_eq!(platform.os_version.as_deref(), Some("14"));
+        assert_eq!(platform.major_upgrades, Some(7));
+        assert_eq!(platform.os_skin.as_deref(), Some("One UI 6.1"));
+    }
+    
+    #[test]
+    fn os_upgradable_to() {
+        let mut platform = PlatformSpecs::default();
+        parse_os("iOS 17, upgradable to iOS 18.1", &mut platform);
+        
+        assert_eq!(platform.os_family.as_deref(), Some("iOS"));
+        assert_eq!(platform.upgradable_to.as_deref(), Some("iOS 18.1"));
+    }
+    
+    #[test]
+    fn multi_word_os_families() {
+        for (text, family, version) in [
+            ("Windows Phone 8.1", "Windows Phone", Some("8.1")),
+            ("Fire OS 8 (Android 11)", "Fire OS", Some("8")),
+            ("Firefox OS", "Firefox OS", None),
+            ("Android (Go edition)", "Android", None),
+        ] {
+            let mut platform = PlatformSpecs::default();
+            parse_os(text, &mut platform);
+            
+            assert_eq!(platform.os_family.as_deref(), Some(family), "{}", text);
+            assert_eq!(platform.os_version.as_deref(), version, "{}", text);
+        }
+    }
+    
+    #[test]
+    fn chipset_vendor_process_and_region() {
+        let chipset = parse_chipset("Qualcomm SM8650-AC Snapdragon 8 Gen 3 (4 nm) - USA/Canada/China");
+        
+        assert_eq!(chipset.vendor.as_deref(), Some("Qualcomm"));
+        assert_eq!(chipset.model, "SM8650-AC Snapdragon 8 Gen 3");
+        assert_eq!(chipset.process_nm, Some(4.0));
+        assert_eq!(chipset.region.as_deref(), Some("USA/Canada/China"));
+        
+        assert_eq!(parse_chipset("Exynos 2400 (4 nm)").vendor.as_deref(), Some("Samsung"));
+    }
+    
+    #[test]
+    fn cpu_clusters() {
+        let cpu = parse_cpu("Octa-core (1x3.39GHz Cortex-X4 & 3x3.1GHz Cortex-A720 & 4x2.2GHz Cortex-A520) - International");
+        
+        assert_eq!(cpu.cores, Some(8));
+        assert_eq!(cpu.clusters.len(), 3);
+        assert_eq!(cpu.clusters[0].core.as_deref(), Some("Cortex-X4"));
+        assert_eq!(cpu.max_clock_ghz, Some(3.39));
+        assert_eq!(cpu.region.as_deref(), Some("International"));
+        
+        assert_eq!(parse_cpu("Octa-core 2.0 GHz Cortex-A53").max_clock_ghz, Some(2.0));
+    }
+    
+    #[test]
+    fn gpu_name() {
+        assert_eq!(parse_gpu_name("Adreno 750 (1 GHz) - USA/Canada/China"), "Adreno 750");
+    }
+}
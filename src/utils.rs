@@ -1,11 +1,245 @@
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{Write, BufReader, BufWriter};
 use std::path::Path;
 use csv::{ReaderBuilder, WriterBuilder};
-use crate::models::{Phone, PhoneDetails};
+use regex::Regex;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use crate::models::{BatteryChemistry, CameraPosition, DeviceGraph, DisplayRole, ExchangeRates, FieldOverride, ImageAsset, LifecycleStatus, Phone, PhoneDetails, PopularityTrend, PriceObservation, RankingEntry, Review};
+use crate::specs::memory::format_gb;
 
+// Extrai o ID do GSMArena a partir da URL (ex: samsung_galaxy_s24-12773.php -> 12773)
+pub fn device_id_from_url(url: &str) -> Option<u32> {
+    let filename = url.split('/').next_back()?;
+    
+    Regex::new(r"-(\d+)\.php$")
+        .ok()?
+        .captures(filename)
+        .and_then(|cap| cap[1].parse().ok())
+}
+
+// Marcas cujo nome não é o slug capitalizado (siglas, CamelCase) ou cujo slug tem mais de uma palavra
+const BRAND_NAMES: &[(&str, &str)] = &[
+    ("at_t", "AT&T"),
+    ("benq", "BenQ"),
+    ("benq_siemens", "BenQ-Siemens"),
+    ("blackberry", "BlackBerry"),
+    ("blu", "BLU"),
+    ("bq", "BQ"),
+    ("fujitsu_siemens", "Fujitsu Siemens"),
+    ("garmin_asus", "Garmin-Asus"),
+    ("hmd", "HMD"),
+    ("hp", "HP"),
+    ("htc", "HTC"),
+    ("i_mate", "i-mate"),
+    ("i_mobile", "i-mobile"),
+    ("inq", "INQ"),
+    ("leeco", "LeEco"),
+    ("lg", "LG"),
+    ("mwg", "MWg"),
+    ("nec", "NEC"),
+    ("niu", "NIU"),
+    ("o2", "O2"),
+    ("oneplus", "OnePlus"),
+    ("qmobile", "QMobile"),
+    ("sony_ericsson", "Sony Ericsson"),
+    ("t_mobile", "T-Mobile"),
+    ("tcl", "TCL"),
+    ("tel_me", "Tel.Me."),
+    ("vk_mobile", "VK Mobile"),
+    ("wnd", "WND"),
+    ("xolo", "XOLO"),
+    ("yu", "YU"),
+    ("zte", "ZTE"),
+];
+
+fn url_slug(url: &str) -> &str {
+    url.split('/').next_back().unwrap_or(url).trim_end_matches(".php")
+}
+
+// Entrada mais longa primeiro: "benq_siemens_..." não é "BenQ"
+fn known_brand(slug: &str) -> Option<&'static (&'static str, &'static str)> {
+    BRAND_NAMES.iter()
+        .filter(|(brand_slug, _)| slug.starts_with(&format!("{}_", brand_slug)))
+        .max_by_key(|(brand_slug, _)| brand_slug.len())
+}
+
+// Infere a marca a partir do slug (ex: samsung_galaxy_s24-12773.php -> Samsung, lg_g8-9593.php -> LG)
+pub fn brand_from_url(url: &str) -> Option<String> {
+    let slug = url_slug(url);
+    
+    if let Some((_, name)) = known_brand(slug) {
+        return Some(name.to_string());
+    }
+    
+    let word = slug.split('_').next().filter(|s| !s.is_empty())?;
+    let mut chars = word.chars();
+    let brand = match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    };
+    
+    Some(brand)
+}
+
+// Quantas palavras do slug pertencem à marca (ex: "t_mobile_g1" -> 2, "samsung_galaxy_s24" -> 1)
+pub fn brand_slug_words(url: &str) -> usize {
+    known_brand(url_slug(url))
+        .map(|(brand_slug, _)| brand_slug.split('_').count())
+        .unwrap_or(1)
+}
+
+// Subpáginas do aparelho: "modelo-12773.php" -> "modelo-<kind>-12773.php" (pictures, reviews, price)
+pub fn device_subpage_url(phone_url: &str, kind: &str) -> Option<String> {
+    let (prefix, id) = phone_url.trim_end_matches(".php").rsplit_once('-')?;
+    id.parse::<u32>().ok()?;
+    
+    Some(format!("{}-{}-{}.php", prefix, kind, id))
+}
+
+// Verifica se a URL é a página de especificações de um aparelho
+// (e não reviews, fotos, preços, notícias ou listagens da marca)
+pub fn is_device_spec_url(url: &str) -> bool {
+    let Some(filename) = url.split('/').next_back() else {
+        return false;
+    };
+    
+    let non_spec_markers = [
+        "-reviews-", "-review-", "-pictures-", "-price-", "-news-",
+        "-phones-", "-3d-spin-", "-related-", "-compare-",
+    ];
+    
+    device_id_from_url(filename).is_some()
+        && filename.contains('_')
+        && !non_spec_markers.iter().any(|marker| filename.contains(marker))
+}
 
+// Página N de uma subpágina paginada: "modelo-review-2664.php" -> "modelo-review-2664p2.php"
+pub fn paged_url(first_page_url: &str, page: usize) -> String {
+    if page <= 1 {
+        return first_page_url.to_string();
+    }
+    
+    format!("{}p{}.php", first_page_url.trim_end_matches(".php"), page)
+}
+
+// Maior "pN" entre os links de paginação (a primeira página não tem sufixo).
+// `marker` separa a review ("-review-") das opiniões ("-reviews-")
+pub fn page_count(html: &str, marker: &str) -> usize {
+    let Ok(re) = Regex::new(&format!(r"{}\d+p(\d+)\.php", regex::escape(marker))) else {
+        return 1;
+    };
+    
+    re.captures_iter(html)
+        .filter_map(|cap| cap[1].parse().ok())
+        .max()
+        .unwrap_or(1)
+}
+
+// Área em cm² a partir da diagonal e da proporção (assume um retângulo perfeito)
+pub fn calculate_display_area(size_text: &str, ratio_text: &str) -> Option<f64> {
+    let size_re = Regex::new(r"(\d+\.?\d*)").ok()?;
+    let size_cap = size_re.captures(size_text)?;
+    let diagonal_inches: f64 = size_cap[1].parse().ok()?;
+    
+    let ratio_re = Regex::new(r"(\d+\.?\d*)\s*[:]\s*(\d+\.?\d*)").ok()?;
+    let ratio_cap = ratio_re.captures(ratio_text)?;
+    
+    let a: f64 = ratio_cap[1].parse().ok()?;
+    let b: f64 = ratio_cap[2].parse().ok()?;
+    
+    let ratio = b / a;
+    let width_inches = diagonal_inches / (1.0 + ratio * ratio).sqrt();
+    let height_inches = width_inches * ratio;
+    
+    let width_cm = width_inches * 2.54;
+    let height_cm = height_inches * 2.54;
+    
+    Some(width_cm * height_cm)
+}
 
+// Grava a imagem em <store>/<2 primeiros hex>/<sha256>.<ext>; conteúdo repetido não é regravado
+pub fn store_image(bytes: &[u8], source_url: &str, store_dir: &str) -> Result<ImageAsset, Box<dyn std::error::Error>> {
+    let hash = format!("{:x}", Sha256::digest(bytes));
+    let extension = source_url.rsplit('/').next()
+        .and_then(|name| name.split('?').next())
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_lowercase())
+        .filter(|ext| ext.len() <= 4 && !ext.is_empty())
+        .unwrap_or_else(|| "img".to_string());
+    
+    let dir = Path::new(store_dir).join(&hash[..2]);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.{}", hash, extension));
+    
+    if !path.exists() {
+        std::fs::write(&path, bytes)?;
+    }
+    
+    let size = imagesize::blob_size(bytes).ok();
+    
+    Ok(ImageAsset {
+        source_url: source_url.to_string(),
+        local_path: path.to_string_lossy().into_owned(),
+        sha256: hash,
+        width: size.map(|s| s.width as u32),
+        height: size.map(|s| s.height as u32),
+        bytes: bytes.len() as u64,
+    })
+}
+
+// Correções manuais: CSV "device_id,field,value"
+pub fn load_overrides(filename: &str) -> Result<Vec<FieldOverride>, Box<dyn std::error::Error>> {
+    let mut rdr = csv::Reader::from_path(filename)?;
+    let mut overrides = Vec::new();
+    
+    for result in rdr.deserialize() {
+        let field_override: FieldOverride = result?;
+        overrides.push(field_override);
+    }
+    
+    Ok(overrides)
+}
+
+pub fn apply_overrides(details: &mut [PhoneDetails], overrides: &[FieldOverride]) -> usize {
+    let mut applied = 0;
+    
+    for detail in details.iter_mut() {
+        let Some(device_id) = device_id_from_url(&detail.url) else {
+            continue;
+        };
+        
+        for field_override in overrides.iter().filter(|o| o.device_id == device_id) {
+            if detail.apply_override(&field_override.field, &field_override.value) {
+                applied += 1;
+            } else {
+                println!("⚠️  Campo sem suporte a override: {}", field_override.field);
+            }
+        }
+    }
+    
+    applied
+}
+
+// Tabela de câmbio local (CSV "currency,rate"), sem API externa
+pub fn load_exchange_rates(filename: &str, base_currency: &str) -> Result<ExchangeRates, Box<dyn std::error::Error>> {
+    let mut rdr = csv::Reader::from_path(filename)?;
+    let mut rates = ExchangeRates {
+        base_currency: base_currency.to_uppercase(),
+        rates: HashMap::new(),
+    };
+    
+    for result in rdr.records() {
+        let record = result?;
+        if let (Some(currency), Some(rate)) = (record.get(0), record.get(1))
+            && let Ok(rate) = rate.trim().parse::<f64>() {
+            rates.rates.insert(currency.trim().to_uppercase(), rate);
+        }
+    }
+    
+    Ok(rates)
+}
 
 pub fn save_phones_to_csv(phones: &[Phone], filename: &str) -> Result<(), Box<dyn std::error::Error>> {
     let file = File::create(filename)?;
@@ -49,7 +283,7 @@ pub fn save_details_to_csv(details: &[PhoneDetails], filename: &str) -> Result<(
         .from_writer(BufWriter::new(file));
     
     for detail in details {
-        wtr.serialize(detail)?;
+        wtr.serialize(DetailsCsvRow::from(detail))?;
     }
     
     wtr.flush()?;
@@ -57,6 +291,446 @@ pub fn save_details_to_csv(details: &[PhoneDetails], filename: &str) -> Result<(
     Ok(())
 }
 
+// JSON mantém as listas e estruturas aninhadas que o CSV precisa achatar
+pub fn save_details_to_json(details: &[PhoneDetails], filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::create(filename)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), details)?;
+    
+    println!("✅ Detalhes salvos em: {}", filename);
+    Ok(())
+}
+
+// Histórico de preços: acrescenta ao CSV existente (cabeçalho só na criação)
+// Mesma oferta com o mesmo valor no mesmo dia entra uma vez só (coletas repetidas no dia)
+fn price_key(observation: &PriceObservation) -> (u32, String, String, Option<String>, String, u64, String) {
+    (
+        observation.device_id,
+        observation.store.clone(),
+        observation.region.clone(),
+        observation.variant.clone(),
+        observation.currency.clone(),
+        observation.amount.to_bits(),
+        observation.observed_at.chars().take(10).collect(), // AAAA-MM-DD
+    )
+}
+
+// Retorna quantas observações foram de fato adicionadas
+pub fn append_price_history(observations: &[PriceObservation], filename: &str) -> Result<usize, Box<dyn std::error::Error>> {
+    let is_new = !Path::new(filename).exists();
+    
+    let mut seen = HashSet::new();
+    if !is_new {
+        let mut rdr = ReaderBuilder::new()
+            .has_headers(true)
+            .from_reader(BufReader::new(File::open(filename)?));
+        
+        for result in rdr.deserialize() {
+            let observation: PriceObservation = result?;
+            seen.insert(price_key(&observation));
+        }
+    }
+    
+    let file = std::fs::OpenOptions::new().create(true).append(true).open(filename)?;
+    let mut wtr = WriterBuilder::new()
+        .has_headers(is_new)
+        .from_writer(BufWriter::new(file));
+    
+    let mut added = 0;
+    for observation in observations {
+        if seen.insert(price_key(observation)) {
+            wtr.serialize(observation)?;
+            added += 1;
+        }
+    }
+    
+    wtr.flush()?;
+    println!("💾 {} preços adicionados ao histórico ({} repetidos): {}", added, observations.len() - added, filename);
+    Ok(added)
+}
+
+// Snapshots dos rankings acumulam no mesmo CSV (série temporal)
+pub fn append_rankings(entries: &[RankingEntry], filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let is_new = !Path::new(filename).exists();
+    let file = std::fs::OpenOptions::new().create(true).append(true).open(filename)?;
+    let mut wtr = WriterBuilder::new()
+        .has_headers(is_new)
+        .from_writer(BufWriter::new(file));
+    
+    for entry in entries {
+        wtr.serialize(entry)?;
+    }
+    
+    wtr.flush()?;
+    println!("🏆 {} posições salvas em: {}", entries.len(), filename);
+    Ok(())
+}
+
+pub fn save_reviews_to_json(reviews: &[Review], filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::create(filename)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), reviews)?;
+    
+    println!("✅ Reviews salvas em: {}", filename);
+    Ok(())
+}
+
+pub fn save_graph_to_json(graph: &DeviceGraph, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let file = File::create(filename)?;
+    serde_json::to_writer_pretty(BufWriter::new(file), graph)?;
+    
+    println!("✅ Grafo salvo em: {} ({} nós, {} arestas)", filename, graph.nodes.len(), graph.edges.len());
+    Ok(())
+}
+
+pub fn save_graph_to_graphml(graph: &DeviceGraph, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut file = BufWriter::new(File::create(filename)?);
+    
+    writeln!(file, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(file, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+    writeln!(file, r#"  <key id="model" for="node" attr.name="model" attr.type="string"/>"#)?;
+    writeln!(file, r#"  <key id="url" for="node" attr.name="url" attr.type="string"/>"#)?;
+    writeln!(file, r#"  <key id="depth" for="node" attr.name="depth" attr.type="int"/>"#)?;
+    writeln!(file, r#"  <key id="kind" for="edge" attr.name="kind" attr.type="string"/>"#)?;
+    writeln!(file, r#"  <graph id="devices" edgedefault="directed">"#)?;
+    
+    for node in graph.nodes.values() {
+        writeln!(file, r#"    <node id="{}">"#, node.id)?;
+        if let Some(model) = &node.model {
+            writeln!(file, r#"      <data key="model">{}</data>"#, escape_xml(model))?;
+        }
+        if let Some(url) = &node.url {
+            writeln!(file, r#"      <data key="url">{}</data>"#, escape_xml(url))?;
+        }
+        writeln!(file, r#"      <data key="depth">{}</data>"#, node.depth)?;
+        writeln!(file, "    </node>")?;
+    }
+    
+    for edge in &graph.edges {
+        writeln!(file, r#"    <edge source="{}" target="{}"><data key="kind">{:?}</data></edge>"#,
+            edge.source, edge.target, edge.kind)?;
+    }
+    
+    writeln!(file, "  </graph>")?;
+    writeln!(file, "</graphml>")?;
+    file.flush()?;
+    
+    println!("✅ Grafo salvo em: {}", filename);
+    Ok(())
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Índice reverso: código de modelo -> ID do aparelho no GSMArena
+#[derive(Serialize)]
+struct ModelIndexRow<'a> {
+    model_code: &'a str,
+    device_id: Option<u32>,
+    model: &'a str,
+    url: &'a str,
+}
+
+pub fn save_model_index(details: &[PhoneDetails], filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut rows: Vec<ModelIndexRow> = details.iter()
+        .flat_map(|detail| {
+            detail.model_codes.iter().map(move |code| ModelIndexRow {
+                model_code: code,
+                device_id: device_id_from_url(&detail.url),
+                model: &detail.model,
+                url: &detail.url,
+            })
+        })
+        .collect();
+    rows.sort_by(|a, b| a.model_code.cmp(b.model_code));
+    
+    let mut wtr = WriterBuilder::new().has_headers(true).from_path(filename)?;
+    for row in &rows {
+        wtr.serialize(row)?;
+    }
+    wtr.flush()?;
+    
+    println!("🔎 Índice de modelos salvo em: {} ({} códigos)", filename, rows.len());
+    Ok(())
+}
+
+// Linha achatada do CSV: o crate csv não serializa listas nem structs aninhadas
+#[derive(Serialize)]
+struct DetailsCsvRow<'a> {
+    phone_id: usize,
+    model: &'a str,
+    url: &'a str,
+    display_ratio: Option<&'a str>,
+    display_area_cm2: Option<&'a str>,
+    resolution: Option<&'a str>,
+    screen_size: Option<&'a str>,
+    ppi: Option<&'a str>,
+    display_type: Option<&'a str>,
+    refresh_rate_hz: Option<u32>,
+    hdr_formats: String,
+    brightness_typical_nits: Option<u32>,
+    brightness_peak_nits: Option<u32>,
+    display_protection: Option<&'a str>,
+    displays: String,
+    dimensions: String,
+    height_mm: Option<f64>,
+    width_mm: Option<f64>,
+    thickness_mm: Option<f64>,
+    weight_g: Option<f64>,
+    front_material: Option<&'a str>,
+    back_material: Option<&'a str>,
+    frame_material: Option<&'a str>,
+    sim: Option<&'a str>,
+    esim: bool,
+    dual_sim: bool,
+    ip_ratings: String,
+    mil_std: Option<&'a str>,
+    screen_to_body_pct: Option<String>,
+    reported_area_cm2: Option<f64>,
+    reported_screen_to_body_pct: Option<f64>,
+    area_discrepancy_cm2: Option<String>,
+    area_mismatch: bool,
+    screen_width_mm: Option<String>,
+    screen_height_mm: Option<String>,
+    screen_width_in: Option<String>,
+    screen_height_in: Option<String>,
+    pixel_pitch_mm: Option<String>,
+    device_pixel_ratio: Option<f64>,
+    css_viewport: Option<String>,
+    announced: Option<String>,
+    released: Option<String>,
+    lifecycle_status: Option<LifecycleStatus>,
+    os: Option<&'a str>,
+    os_family: Option<&'a str>,
+    os_version: Option<&'a str>,
+    major_upgrades: Option<u32>,
+    chipset: Option<&'a str>,
+    chipset_vendor: Option<&'a str>,
+    chipset_model: Option<&'a str>,
+    process_nm: Option<f64>,
+    cpu_cores: Option<u32>,
+    cpu_max_clock_ghz: Option<f64>,
+    gpu: String,
+    ram: Option<&'a str>,
+    storage: Option<&'a str>,
+    memory_variants: String,
+    min_ram_gb: Option<f64>,
+    max_ram_gb: Option<f64>,
+    min_storage_gb: Option<f64>,
+    max_storage_gb: Option<f64>,
+    storage_types: String,
+    card_slot_supported: Option<bool>,
+    main_cameras: String,
+    main_camera_max_mp: Option<f64>,
+    main_video: Option<String>,
+    selfie_cameras: String,
+    selfie_camera_max_mp: Option<f64>,
+    selfie_video: Option<String>,
+    battery: Option<&'a str>,
+    battery_mah: Option<u32>,
+    battery_chemistry: Option<BatteryChemistry>,
+    battery_removable: Option<bool>,
+    wired_charging_w: Option<f64>,
+    wireless_charging_w: Option<f64>,
+    reverse_charging: bool,
+    charge_claims: String,
+    has_2g: bool,
+    has_3g: bool,
+    has_4g: bool,
+    has_5g: bool,
+    bands_5g: String,
+    wifi_standard: Option<&'a str>,
+    bluetooth_version: Option<&'a str>,
+    nfc: Option<bool>,
+    usb_connector: Option<&'a str>,
+    usb_version: Option<&'a str>,
+    positioning: String,
+    infrared: Option<bool>,
+    fm_radio: Option<bool>,
+    headphone_jack: Option<bool>,
+    sensors: String,
+    eu_energy_class: Option<&'a str>,
+    eu_battery_endurance_hours: Option<f64>,
+    eu_battery_cycles: Option<u32>,
+    eu_free_fall_class: Option<&'a str>,
+    eu_repairability_class: Option<&'a str>,
+    antutu: Option<f64>,
+    geekbench: Option<f64>,
+    three_dmark: Option<f64>,
+    contrast_ratio: Option<&'a str>,
+    loudspeaker_lufs: Option<f64>,
+    battery_active_use_hours: Option<f64>,
+    battery_endurance_hours: Option<f64>,
+    model_codes: String,
+    hits: Option<u64>,
+    popularity_percent: Option<f64>,
+    popularity_trend: Option<PopularityTrend>,
+    fans: Option<u32>,
+    opinions_count: Option<u32>,
+    fetched_at: Option<&'a str>,
+    thumbnail_path: Option<&'a str>,
+    picture_count: usize,
+    prices: String,
+    price_base: Option<f64>,
+    price_base_currency: Option<&'a str>,
+    low_confidence_fields: String,
+    validation_issues: String,
+    status_code: u16,
+    error_message: Option<&'a str>,
+}
+
+impl<'a> From<&'a PhoneDetails> for DetailsCsvRow<'a> {
+    fn from(detail: &'a PhoneDetails) -> Self {
+        let eu_label = detail.tests.eu_label.as_ref();
+        let main_display = detail.displays.iter().find(|d| d.role == DisplayRole::Main);
+        let geometry = main_display.and_then(|d| d.geometry.as_ref());
+        
+        Self {
+            phone_id: detail.phone_id,
+            model: &detail.model,
+            url: &detail.url,
+            display_ratio: detail.display_ratio.as_deref(),
+            display_area_cm2: detail.display_area_cm2.as_deref(),
+            resolution: detail.resolution.as_deref(),
+            screen_size: detail.screen_size.as_deref(),
+            ppi: detail.ppi.as_deref(),
+            display_type: detail.display_type.as_deref(),
+            refresh_rate_hz: detail.refresh_rate_hz,
+            hdr_formats: detail.hdr_formats.join("; "),
+            brightness_typical_nits: detail.brightness_typical_nits,
+            brightness_peak_nits: detail.brightness_peak_nits,
+            display_protection: detail.display_protection.as_deref(),
+            displays: detail.displays.iter().map(|d| d.summary()).collect::<Vec<_>>().join(" | "),
+            dimensions: detail.body.dimensions.iter().map(|d| d.summary()).collect::<Vec<_>>().join(" | "),
+            height_mm: detail.body.dimensions.first().map(|d| d.height_mm),
+            width_mm: detail.body.dimensions.first().map(|d| d.width_mm),
+            thickness_mm: detail.body.dimensions.first().map(|d| d.thickness_mm),
+            weight_g: detail.body.weight_g,
+            front_material: detail.body.front_material.as_deref(),
+            back_material: detail.body.back_material.as_deref(),
+            frame_material: detail.body.frame_material.as_deref(),
+            sim: detail.body.sim.as_deref(),
+            esim: detail.body.esim,
+            dual_sim: detail.body.dual_sim,
+            ip_ratings: detail.body.ip_ratings.join("; "),
+            mil_std: detail.body.mil_std.as_deref(),
+            screen_to_body_pct: detail.screen_to_body_ratio().map(|r| format!("{:.1}", r)),
+            reported_area_cm2: main_display.and_then(|d| d.reported_area_cm2),
+            reported_screen_to_body_pct: main_display.and_then(|d| d.reported_screen_to_body),
+            area_discrepancy_cm2: main_display.and_then(|d| d.area_discrepancy_cm2).map(|d| format!("{:.2}", d)),
+            area_mismatch: main_display.is_some_and(|d| d.area_mismatch),
+            screen_width_mm: geometry.map(|g| format!("{:.1}", g.width_mm)),
+            screen_height_mm: geometry.map(|g| format!("{:.1}", g.height_mm)),
+            screen_width_in: geometry.map(|g| format!("{:.2}", g.width_in)),
+            screen_height_in: geometry.map(|g| format!("{:.2}", g.height_in)),
+            pixel_pitch_mm: geometry.map(|g| format!("{:.4}", g.pixel_pitch_mm)),
+            device_pixel_ratio: geometry.map(|g| g.device_pixel_ratio),
+            css_viewport: geometry.map(|g| g.css_viewport()),
+            announced: detail.announced.map(|d| d.to_string()),
+            released: detail.released.map(|d| d.to_string()),
+            lifecycle_status: detail.lifecycle_status,
+            os: detail.os.as_deref(),
+            os_family: detail.platform.os_family.as_deref(),
+            os_version: detail.platform.os_version.as_deref(),
+            major_upgrades: detail.platform.major_upgrades,
+            chipset: detail.chipset.as_deref(),
+            chipset_vendor: detail.platform.chipsets.first().and_then(|c| c.vendor.as_deref()),
+            chipset_model: detail.platform.chipsets.first().map(|c| c.model.as_str()),
+            process_nm: detail.platform.chipsets.first().and_then(|c| c.process_nm),
+            cpu_cores: detail.platform.cpus.first().and_then(|c| c.cores),
+            cpu_max_clock_ghz: detail.platform.cpus.first().and_then(|c| c.max_clock_ghz),
+            gpu: detail.platform.gpus.join("; "),
+            ram: detail.ram.as_deref(),
+            storage: detail.storage.as_deref(),
+            memory_variants: detail.memory.variants.iter()
+                .map(|v| format!(
+                    "{} {} RAM",
+                    v.storage_gb.map(format_gb).unwrap_or_default(),
+                    v.ram_gb.map(format_gb).unwrap_or_default(),
+                ).trim().to_string())
+                .collect::<Vec<_>>()
+                .join("; "),
+            min_ram_gb: detail.memory.min_ram_gb(),
+            max_ram_gb: detail.memory.max_ram_gb(),
+            min_storage_gb: detail.memory.min_storage_gb(),
+            max_storage_gb: detail.memory.max_storage_gb(),
+            storage_types: detail.memory.storage_types.join("; "),
+            card_slot_supported: detail.memory.card_slot_supported,
+            main_cameras: detail.camera.modules_at(CameraPosition::Rear)
+                .map(|m| m.summary())
+                .collect::<Vec<_>>()
+                .join("; "),
+            main_camera_max_mp: detail.camera.max_megapixels(CameraPosition::Rear),
+            main_video: detail.camera.main_video.as_ref().map(|v| v.summary()),
+            selfie_cameras: detail.camera.modules_at(CameraPosition::Front)
+                .map(|m| m.summary())
+                .collect::<Vec<_>>()
+                .join("; "),
+            selfie_camera_max_mp: detail.camera.max_megapixels(CameraPosition::Front),
+            selfie_video: detail.camera.selfie_video.as_ref().map(|v| v.summary()),
+            battery: detail.battery.as_deref(),
+            battery_mah: detail.battery_specs.capacity_mah,
+            battery_chemistry: detail.battery_specs.chemistry,
+            battery_removable: detail.battery_specs.removable,
+            wired_charging_w: detail.battery_specs.wired_w,
+            wireless_charging_w: detail.battery_specs.wireless_w,
+            reverse_charging: detail.battery_specs.reverse_charging,
+            charge_claims: detail.battery_specs.charge_claims.iter()
+                .map(|c| format!("{}% in {} min", c.percent, c.minutes))
+                .collect::<Vec<_>>()
+                .join("; "),
+            has_2g: detail.connectivity.has_2g,
+            has_3g: detail.connectivity.has_3g,
+            has_4g: detail.connectivity.has_4g,
+            has_5g: detail.connectivity.has_5g,
+            bands_5g: detail.connectivity.bands_5g.join("; "),
+            wifi_standard: detail.connectivity.wifi_standard.as_deref(),
+            bluetooth_version: detail.connectivity.bluetooth_version.as_deref(),
+            nfc: detail.connectivity.nfc,
+            usb_connector: detail.connectivity.usb_connector.as_deref(),
+            usb_version: detail.connectivity.usb_version.as_deref(),
+            positioning: detail.connectivity.positioning.join("; "),
+            infrared: detail.connectivity.infrared,
+            fm_radio: detail.connectivity.fm_radio,
+            headphone_jack: detail.connectivity.headphone_jack,
+            sensors: detail.connectivity.sensors.join("; "),
+            eu_energy_class: eu_label.and_then(|l| l.energy_class.as_deref()),
+            eu_battery_endurance_hours: eu_label.and_then(|l| l.battery_endurance_hours),
+            eu_battery_cycles: eu_label.and_then(|l| l.battery_cycles),
+            eu_free_fall_class: eu_label.and_then(|l| l.free_fall_class.as_deref()),
+            eu_repairability_class: eu_label.and_then(|l| l.repairability_class.as_deref()),
+            antutu: detail.tests.benchmark("AnTuTu"),
+            geekbench: detail.tests.benchmark("GeekBench"),
+            three_dmark: detail.tests.benchmark("3DMark"),
+            contrast_ratio: detail.tests.contrast_ratio.as_deref(),
+            loudspeaker_lufs: detail.tests.loudspeaker_lufs,
+            battery_active_use_hours: detail.tests.battery_active_use_hours,
+            battery_endurance_hours: detail.tests.battery_endurance_hours,
+            model_codes: detail.model_codes.join("; "),
+            hits: detail.engagement.hits,
+            popularity_percent: detail.engagement.popularity_percent,
+            popularity_trend: detail.engagement.trend,
+            fans: detail.engagement.fans,
+            opinions_count: detail.engagement.opinions_count,
+            fetched_at: detail.fetched_at.as_deref(),
+            thumbnail_path: detail.thumbnail.as_ref().map(|t| t.local_path.as_str()),
+            picture_count: detail.pictures.len(),
+            prices: detail.prices.iter().map(|p| p.summary()).collect::<Vec<_>>().join("; "),
+            price_base: detail.price_base.as_ref().map(|p| p.amount),
+            price_base_currency: detail.price_base.as_ref().map(|p| p.currency.as_str()),
+            low_confidence_fields: detail.low_confidence_fields().join("; "),
+            validation_issues: detail.validation_issues.iter()
+                .map(|i| format!("{}: {}", i.field, i.message))
+                .collect::<Vec<_>>()
+                .join("; "),
+            status_code: detail.status_code,
+            error_message: detail.error_message.as_deref(),
+        }
+    }
+}
+
 pub fn save_details_to_txt(details: &[PhoneDetails], filename: &str) -> Result<(), Box<dyn std::error::Error>> {
     let mut file = File::create(filename)?;
     
@@ -84,6 +758,86 @@ pub fn save_details_to_txt(details: &[PhoneDetails], filename: &str) -> Result<(
         writeln!(file, "Resolução: {}", detail.resolution.as_deref().unwrap_or("N/A"))?;
         writeln!(file, "Tamanho: {}", detail.screen_size.as_deref().unwrap_or("N/A"))?;
         writeln!(file, "PPI: {}", detail.ppi.as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Painel: {}", detail.display_type.as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Taxa de atualização: {}", detail.refresh_rate_hz.map(|hz| format!("{} Hz", hz)).as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "HDR: {}", if detail.hdr_formats.is_empty() { "N/A".to_string() } else { detail.hdr_formats.join(", ") })?;
+        writeln!(file, "Brilho: {} nits (típico) / {} nits (pico)",
+            detail.brightness_typical_nits.map(|n| n.to_string()).as_deref().unwrap_or("N/A"),
+            detail.brightness_peak_nits.map(|n| n.to_string()).as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Proteção: {}", detail.display_protection.as_deref().unwrap_or("N/A"))?;
+        for display in &detail.displays {
+            writeln!(file, "Tela: {}", display.summary())?;
+            if let (Some(reported), Some(discrepancy)) = (display.reported_area_cm2, display.area_discrepancy_cm2) {
+                writeln!(file, "  Área informada: {:.1} cm² (diferença {:+.2} cm²)", reported, discrepancy)?;
+            }
+            if let Some(geometry) = &display.geometry {
+                writeln!(file, "  Medidas: {:.1} x {:.1} mm, pitch {:.4} mm, DPR {}, viewport CSS {}",
+                    geometry.width_mm, geometry.height_mm, geometry.pixel_pitch_mm,
+                    geometry.device_pixel_ratio, geometry.css_viewport())?;
+            }
+        }
+        for dimensions in &detail.body.dimensions {
+            writeln!(file, "Dimensões: {}", dimensions.summary())?;
+        }
+        writeln!(file, "Peso: {}", detail.body.weight_g.map(|w| format!("{} g", w)).as_deref().unwrap_or("N/A"))?;
+        let mut certifications = detail.body.ip_ratings.clone();
+        certifications.extend(detail.body.mil_std.clone());
+        writeln!(file, "Certificações: {}", if certifications.is_empty() { "N/A".to_string() } else { certifications.join(", ") })?;
+        writeln!(file, "Screen-to-body (calculado): {}", detail.screen_to_body_ratio().map(|r| format!("{:.1}%", r)).as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "OS: {}", detail.os.as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Chipset: {}", detail.chipset.as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "GPU: {}", if detail.platform.gpus.is_empty() { "N/A".to_string() } else { detail.platform.gpus.join(", ") })?;
+        writeln!(file, "RAM: {}", detail.ram.as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Armazenamento: {}", detail.storage.as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Cartão de memória: {}", detail.memory.card_slot.as_deref().unwrap_or("N/A"))?;
+        for module in &detail.camera.modules {
+            writeln!(file, "Câmera {:?}: {}", module.position, module.summary())?;
+        }
+        writeln!(file, "Vídeo: {}", detail.camera.main_video.as_ref().map(|v| v.summary()).as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Bateria: {}", detail.battery.as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Carregamento: {} W (fio) / {} W (sem fio)",
+            detail.battery_specs.wired_w.map(|w| w.to_string()).as_deref().unwrap_or("N/A"),
+            detail.battery_specs.wireless_w.map(|w| w.to_string()).as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "5G: {} | NFC: {} | Wi-Fi: {} | Bluetooth: {}",
+            if detail.connectivity.has_5g { "Sim" } else { "Não" },
+            detail.connectivity.nfc.map(|nfc| if nfc { "Sim" } else { "Não" }).unwrap_or("N/A"),
+            detail.connectivity.wifi_standard.as_deref().unwrap_or("N/A"),
+            detail.connectivity.bluetooth_version.as_deref().unwrap_or("N/A"))?;
+        if let Some(antutu) = detail.tests.benchmark("AnTuTu") {
+            writeln!(file, "AnTuTu: {}", antutu)?;
+        }
+        if let Some(hours) = detail.tests.battery_active_use_hours {
+            writeln!(file, "Bateria (uso ativo): {:.1}h", hours)?;
+        }
+        if let Some(class) = detail.tests.eu_label.as_ref().and_then(|l| l.energy_class.as_deref()) {
+            writeln!(file, "Classe energética UE: {}", class)?;
+        }
+        if let Some(hits) = detail.engagement.hits {
+            writeln!(file, "Popularidade: {} hits, {} fãs", hits, detail.engagement.fans.unwrap_or(0))?;
+        }
+        if let Some(thumbnail) = &detail.thumbnail {
+            writeln!(file, "Imagens: {} (+{} fotos)", thumbnail.local_path, detail.pictures.len())?;
+        }
+        if !detail.model_codes.is_empty() {
+            writeln!(file, "Modelos: {}", detail.model_codes.join(", "))?;
+        }
+        if !detail.prices.is_empty() {
+            let prices: Vec<String> = detail.prices.iter().map(|p| p.summary()).collect();
+            writeln!(file, "Preço: {}", prices.join(" / "))?;
+        }
+        if let Some(price) = &detail.price_base {
+            writeln!(file, "Preço (base): {}", price.summary())?;
+        }
+        let low_confidence = detail.low_confidence_fields();
+        if !low_confidence.is_empty() {
+            writeln!(file, "Baixa confiança: {}", low_confidence.join(", "))?;
+        }
+        for issue in &detail.validation_issues {
+            writeln!(file, "⚠️  [{:?}] {}: {}", issue.severity, issue.field, issue.message)?;
+        }
+        writeln!(file, "Anunciado: {}", detail.announced.map(|d| d.to_string()).as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Lançado: {}", detail.released.map(|d| d.to_string()).as_deref().unwrap_or("N/A"))?;
+        writeln!(file, "Status: {}", detail.lifecycle_status.map(|s| format!("{:?}", s)).as_deref().unwrap_or("N/A"))?;
         writeln!(file, "URL: {}", detail.url)?;
         writeln!(file, "Status HTTP: {}", detail.status_code)?;
         
@@ -96,4 +850,118 @@ pub fn save_details_to_txt(details: &[PhoneDetails], filename: &str) -> Result<(
     
     println!("📝 Relatório salvo em: {}", filename);
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{GraphEdge, RelationKind};
+    
+    #[test]
+    fn brand_names_from_slugs() {
+        assert_eq!(brand_from_url("https://www.gsmarena.com/samsung_galaxy_s24-12773.php").as_deref(), Some("Samsung"));
+        assert_eq!(brand_from_url("lg_g8_thinq-9593.php").as_deref(), Some("LG"));
+        assert_eq!(brand_from_url("benq_siemens_ef81-1234.php").as_deref(), Some("BenQ-Siemens"));
+        assert_eq!(brand_from_url("benq_a500-1000.php").as_deref(), Some("BenQ"));
+        assert_eq!(brand_from_url("at_t_quickfire-3010.php").as_deref(), Some("AT&T"));
+        assert_eq!(brand_from_url("tel_me_t939-1470.php").as_deref(), Some("Tel.Me."));
+    }
+    
+    #[test]
+    fn brand_words_follow_the_slug_key() {
+        assert_eq!(brand_slug_words("samsung_galaxy_s24-12773.php"), 1);
+        assert_eq!(brand_slug_words("htc_one_m8-6049.php"), 1);
+        assert_eq!(brand_slug_words("t_mobile_g1-2533.php"), 2);
+        assert_eq!(brand_slug_words("at_t_quickfire-3010.php"), 2);
+        assert_eq!(brand_slug_words("i_mate_jasjar-1202.php"), 2);
+        assert_eq!(brand_slug_words("garmin_asus_nuvifone_m10-3105.php"), 2);
+        assert_eq!(brand_slug_words("sony_ericsson_xperia_x10-2962.php"), 2);
+    }
+    
+    fn fixture_graph() -> DeviceGraph {
+        let mut graph = DeviceGraph::default();
+        graph.add_node(1, Some("Galaxy S24".to_string()), Some("https://www.gsmarena.com/samsung_galaxy_s24-12773.php".to_string()), 0);
+        graph.add_node(2, Some("AT&T <Quickfire>".to_string()), None, 1);
+        graph.add_edge(1, 2, RelationKind::Compare);
+        graph
+    }
+    
+    fn temp_file(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("{}_{}", std::process::id(), name))
+            .to_string_lossy()
+            .into_owned()
+    }
+    
+    fn observation(store: &str, amount: f64, observed_at: &str) -> PriceObservation {
+        PriceObservation {
+            observed_at: observed_at.to_string(),
+            device_id: 12773,
+            store: store.to_string(),
+            region: "United States".to_string(),
+            currency: "USD".to_string(),
+            amount,
+            variant: Some("128GB 8GB RAM".to_string()),
+        }
+    }
+    
+    #[test]
+    fn price_history_skips_repeated_observations() {
+        let filename = temp_file("historico_precos.csv");
+        let morning = [
+            observation("Amazon", 799.99, "2024-03-01T09:00:00-03:00"),
+            observation("Best Buy", 799.99, "2024-03-01T09:00:00-03:00"),
+            observation("Amazon", 799.99, "2024-03-01T09:00:00-03:00"),
+        ];
+        assert_eq!(append_price_history(&morning, &filename).unwrap(), 2);
+        
+        // Mesmo dia: só a mudança de preço entra; dia seguinte: tudo entra de novo
+        let afternoon = [
+            observation("Amazon", 799.99, "2024-03-01T15:00:00-03:00"),
+            observation("Best Buy", 749.99, "2024-03-01T15:00:00-03:00"),
+        ];
+        assert_eq!(append_price_history(&afternoon, &filename).unwrap(), 1);
+        
+        let next_day = [observation("Amazon", 799.99, "2024-03-02T09:00:00-03:00")];
+        assert_eq!(append_price_history(&next_day, &filename).unwrap(), 1);
+        
+        let csv = std::fs::read_to_string(&filename).unwrap();
+        std::fs::remove_file(&filename).unwrap();
+        
+        // Cabeçalho uma vez só + 4 observações
+        assert_eq!(csv.lines().count(), 5);
+        assert_eq!(csv.matches("observed_at").count(), 1);
+    }
+    
+    #[test]
+    fn graph_json_round_trip() {
+        let filename = temp_file("grafo.json");
+        save_graph_to_json(&fixture_graph(), &filename).unwrap();
+        
+        let graph: DeviceGraph = serde_json::from_str(&std::fs::read_to_string(&filename).unwrap()).unwrap();
+        std::fs::remove_file(&filename).unwrap();
+        
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[&2].model.as_deref(), Some("AT&T <Quickfire>"));
+        assert_eq!(graph.nodes[&2].depth, 1);
+        assert!(graph.edges.contains(&GraphEdge { source: 1, target: 2, kind: RelationKind::Compare }));
+    }
+    
+    #[test]
+    fn graphml_nodes_edges_and_escaping() {
+        let filename = temp_file("grafo.graphml");
+        save_graph_to_graphml(&fixture_graph(), &filename).unwrap();
+        
+        let xml = std::fs::read_to_string(&filename).unwrap();
+        std::fs::remove_file(&filename).unwrap();
+        
+        assert!(xml.contains(r#"<node id="1">"#));
+        assert!(xml.contains(r#"<data key="url">https://www.gsmarena.com/samsung_galaxy_s24-12773.php</data>"#));
+        assert!(xml.contains(r#"<data key="model">AT&amp;T &lt;Quickfire&gt;</data>"#));
+        assert!(xml.contains(r#"<edge source="1" target="2"><data key="kind">Compare</data></edge>"#));
+        
+        // Nó sem URL não ganha <data key="url">
+        assert_eq!(xml.matches(r#"<data key="url">"#).count(), 1);
+        assert!(xml.trim_end().ends_with("</graphml>"));
+    }
+}
@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DisplayRole, PhoneDetails};
+use crate::specs::display::AREA_TOLERANCE_PCT;
+
+// Faixa plausível de diagonal para smartphones (dobráveis abertos chegam a ~8")
+pub const MIN_DIAGONAL_IN: f64 = 3.0;
+pub const MAX_DIAGONAL_IN: f64 = 9.0;
+// Tolerâncias relativas (%) para os valores recalculados
+pub const RATIO_TOLERANCE_PCT: f64 = 5.0;
+pub const PPI_TOLERANCE_PCT: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Severity {
+    Warning, // Valor suspeito, registro mantido
+    Error,   // Valor implausível, registro pode ser rejeitado/quarentenado
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub field: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+// O que fazer com registros que têm pelo menos um Error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationPolicy {
+    Keep,       // Só anota os avisos
+    Quarantine, // Separa para revisão manual
+    Reject,     // Descarta
+}
+
+impl ValidationIssue {
+    fn warning(field: &str, message: String) -> Self {
+        Self { field: field.to_string(), severity: Severity::Warning, message }
+    }
+    
+    fn error(field: &str, message: String) -> Self {
+        Self { field: field.to_string(), severity: Severity::Error, message }
+    }
+}
+
+// Checagens de plausibilidade sobre os campos de tela (legados e estruturados)
+pub fn validate(detail: &PhoneDetails) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    
+    let diagonal = detail.screen_size.as_deref().and_then(leading_number);
+    let resolution = detail.resolution.as_deref().and_then(parse_resolution);
+    let ratio = detail.display_ratio.as_deref().and_then(parse_ratio);
+    
+    if let Some(size) = diagonal
+        && !(MIN_DIAGONAL_IN..=MAX_DIAGONAL_IN).contains(&size) {
+        issues.push(ValidationIssue::error("screen_size",
+            format!("Diagonal de {}\" fora da faixa {}–{}\"", size, MIN_DIAGONAL_IN, MAX_DIAGONAL_IN)));
+    }
+    
+    // O fallback por regex pega coisas como banners "728x90"
+    if let Some((w, h)) = resolution {
+        let (short, long) = if w <= h { (w, h) } else { (h, w) };
+        if short < 200.0 || long > 4000.0 || long / short > 3.0 {
+            issues.push(ValidationIssue::error("resolution",
+                format!("Resolução {}x{} implausível para um smartphone", w, h)));
+        }
+    }
+    
+    if let (Some((a, b)), Some((w, h))) = (ratio, resolution) {
+        let declared = a.max(b) / a.min(b);
+        let actual = w.max(h) / w.min(h);
+        let diff = relative_diff_pct(declared, actual);
+        
+        if diff > RATIO_TOLERANCE_PCT {
+            issues.push(ValidationIssue::warning("display_ratio",
+                format!("Proporção {:.2} difere da resolução ({:.2}) em {:.1}%", declared, actual, diff)));
+        }
+    }
+    
+    if let (Some(size), Some((w, h))) = (diagonal, resolution)
+        && size > 0.0 {
+        let computed = (w * w + h * h).sqrt() / size;
+        let declared = detail.ppi.as_deref().and_then(leading_number);
+        
+        if let Some(declared) = declared {
+            let diff = relative_diff_pct(declared, computed);
+            if diff > PPI_TOLERANCE_PCT {
+                issues.push(ValidationIssue::warning("ppi",
+                    format!("PPI declarado {} vs recalculado {:.0} ({:.1}%)", declared, computed, diff)));
+            }
+        }
+    }
+    
+    // A área vem de tamanho + proporção; a resolução dá uma proporção independente
+    if let (Some(size), Some((w, h)), Some(area)) = (
+        diagonal,
+        resolution,
+        detail.display_area_cm2.as_deref().and_then(leading_number),
+    ) {
+        let expected = area_from_resolution(size, w, h);
+        let diff = relative_diff_pct(expected, area);
+        if diff > AREA_TOLERANCE_PCT {
+            issues.push(ValidationIssue::warning("display_area_cm2",
+                format!("Área {} cm² inconsistente com diagonal/resolução ({:.2} cm²)", area, expected)));
+        }
+    }
+    
+    for panel in &detail.displays {
+        let field = format!("displays.{:?}", panel.role).to_lowercase();
+        
+        if let Some(size) = panel.size_inches
+            && panel.role == DisplayRole::Main
+            && !(MIN_DIAGONAL_IN..=MAX_DIAGONAL_IN).contains(&size) {
+            issues.push(ValidationIssue::error(&field, format!("Diagonal de {}\" fora da faixa", size)));
+        }
+        
+        if panel.area_mismatch
+            && let (Some(area), Some(reported)) = (panel.area_cm2, panel.reported_area_cm2) {
+            issues.push(ValidationIssue::warning(&field,
+                format!("Área calculada {:.2} cm² vs informada {} cm²", area, reported)));
+        }
+    }
+    
+    issues
+}
+
+// Anota cada registro e separa os que têm Error conforme a política.
+// Retorna os registros retirados (quarentena ou rejeitados)
+pub fn apply(details: &mut Vec<PhoneDetails>, policy: ValidationPolicy) -> Vec<PhoneDetails> {
+    for detail in details.iter_mut() {
+        detail.validation_issues = validate(detail);
+    }
+    
+    if policy == ValidationPolicy::Keep {
+        return Vec::new();
+    }
+    
+    let (bad, good): (Vec<_>, Vec<_>) = std::mem::take(details)
+        .into_iter()
+        .partition(|d| d.validation_issues.iter().any(|i| i.severity == Severity::Error));
+    *details = good;
+    
+    bad
+}
+
+fn leading_number(text: &str) -> Option<f64> {
+    let number: String = text.trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    
+    number.parse().ok()
+}
+
+// "1080x2340" -> (1080, 2340)
+fn parse_resolution(text: &str) -> Option<(f64, f64)> {
+    let (w, h) = text.split_once('x')?;
+    let (w, h) = (leading_number(w)?, leading_number(h)?);
+    
+    (w > 0.0 && h > 0.0).then_some((w, h))
+}
+
+// "19.5:9" -> (19.5, 9)
+fn parse_ratio(text: &str) -> Option<(f64, f64)> {
+    let (a, b) = text.split_once(':')?;
+    let (a, b) = (leading_number(a)?, leading_number(b)?);
+    
+    (a > 0.0 && b > 0.0).then_some((a, b))
+}
+
+// Retângulo com a diagonal informada e a proporção dos pixels, em cm²
+fn area_from_resolution(diagonal_in: f64, w: f64, h: f64) -> f64 {
+    let diagonal_px = (w * w + h * h).sqrt();
+    let width_cm = diagonal_in * w / diagonal_px * 2.54;
+    let height_cm = diagonal_in * h / diagonal_px * 2.54;
+    
+    width_cm * height_cm
+}
+
+fn relative_diff_pct(reference: f64, value: f64) -> f64 {
+    if reference == 0.0 {
+        return 0.0;
+    }
+    
+    (value - reference).abs() / reference * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DisplayPanel;
+    use crate::specs::empty_details;
+    
+    // 6.2" 1080x2340: ~416 ppi, ~94.4 cm²
+    fn plausible() -> PhoneDetails {
+        let mut detail = empty_details();
+        detail.screen_size = Some("6.2 inches".to_string());
+        detail.resolution = Some("1080x2340".to_string());
+        detail.display_ratio = Some("19.5:9".to_string());
+        detail.ppi = Some("416".to_string());
+        detail.display_area_cm2 = Some("94.4 cm2".to_string());
+        detail
+    }
+    
+    fn issues(detail: &PhoneDetails) -> Vec<(String, Severity)> {
+        validate(detail).into_iter().map(|i| (i.field, i.severity)).collect()
+    }
+    
+    #[test]
+    fn plausible_record_has_no_issues() {
+        assert_eq!(issues(&plausible()), []);
+    }
+    
+    #[test]
+    fn diagonal_out_of_range() {
+        let mut detail = plausible();
+        detail.screen_size = Some("12.4 inches".to_string());
+        assert!(issues(&detail).contains(&("screen_size".to_string(), Severity::Error)));
+        
+        detail.screen_size = Some("2.4 inches".to_string());
+        assert!(issues(&detail).contains(&("screen_size".to_string(), Severity::Error)));
+    }
+    
+    #[test]
+    fn banner_resolution_is_implausible() {
+        let mut detail = plausible();
+        detail.resolution = Some("728x90".to_string());
+        assert!(issues(&detail).contains(&("resolution".to_string(), Severity::Error)));
+    }
+    
+    #[test]
+    fn ratio_disagrees_with_resolution() {
+        let mut detail = plausible();
+        detail.display_ratio = Some("16:9".to_string());
+        assert_eq!(issues(&detail), [("display_ratio".to_string(), Severity::Warning)]);
+    }
+    
+    #[test]
+    fn declared_ppi_disagrees_with_recomputed() {
+        let mut detail = plausible();
+        detail.ppi = Some("500".to_string());
+        assert_eq!(issues(&detail), [("ppi".to_string(), Severity::Warning)]);
+    }
+    
+    #[test]
+    fn area_disagrees_with_resolution() {
+        let mut detail = plausible();
+        detail.display_area_cm2 = Some("80.0 cm2".to_string());
+        assert_eq!(issues(&detail), [("display_area_cm2".to_string(), Severity::Warning)]);
+    }
+    
+    #[test]
+    fn per_panel_checks() {
+        let mut detail = plausible();
+        
+        let mut main = DisplayPanel::new(DisplayRole::Main);
+        main.size_inches = Some(12.4);
+        
+        let mut cover = DisplayPanel::new(DisplayRole::Cover);
+        cover.size_inches = Some(1.9);
+        cover.area_cm2 = Some(11.2);
+        cover.reported_area_cm2 = Some(9.0);
+        cover.area_mismatch = true;
+        
+        detail.displays = vec![main, cover];
+        
+        // Diagonal pequena só é erro no painel principal
+        assert_eq!(issues(&detail), [
+            ("displays.main".to_string(), Severity::Error),
+            ("displays.cover".to_string(), Severity::Warning),
+        ]);
+    }
+    
+    fn batch() -> Vec<PhoneDetails> {
+        let mut bad = plausible();
+        bad.resolution = Some("728x90".to_string());
+        
+        let mut suspicious = plausible();
+        suspicious.ppi = Some("500".to_string());
+        
+        vec![plausible(), bad, suspicious]
+    }
+    
+    #[test]
+    fn keep_policy_only_annotates() {
+        let mut details = batch();
+        let removed = apply(&mut details, ValidationPolicy::Keep);
+        
+        assert!(removed.is_empty());
+        assert_eq!(details.len(), 3);
+        assert!(details[1].validation_issues.iter().any(|i| i.severity == Severity::Error));
+    }
+    
+    #[test]
+    fn quarantine_and_reject_take_out_errors_only() {
+        for policy in [ValidationPolicy::Quarantine, ValidationPolicy::Reject] {
+            let mut details = batch();
+            let removed = apply(&mut details, policy);
+            
+            assert_eq!(removed.len(), 1);
+            assert_eq!(removed[0].resolution.as_deref(), Some("728x90"));
+            
+            // Avisos não retiram o registro
+            assert_eq!(details.len(), 2);
+            assert_eq!(details[1].validation_issues.len(), 1);
+        }
+    }
+}